@@ -0,0 +1,284 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::grid::Grid;
+
+/// A puzzle answer, as it would be submitted
+///
+/// Integers compare equal by value whichever variant holds them, so `Integer(5)` equals
+/// `BigInteger(5)`. In JSON each answer is an object keyed by its kind, with big integers
+/// written as strings (so they survive JSON parsers limited to doubles) and images as a list
+/// of `#`/`.` rows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(#[serde(with = "big_integer")] i128),
+    Text(String),
+    Image(Image),
+}
+
+impl Answer {
+    /// The narrowest variant which holds `n`
+    pub fn integer(n: i128) -> Self {
+        match i64::try_from(n) {
+            Ok(n) => Answer::Integer(n),
+            Err(_) => Answer::BigInteger(n),
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(n) => Some(i128::from(*n)),
+            Answer::BigInteger(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Image(a), Answer::Image(b)) => a == b,
+            _ => self.as_integer().is_some() && self.as_integer() == other.as_integer(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Answer::Integer(_) | Answer::BigInteger(_) => {
+                0u8.hash(state);
+                self.as_integer().hash(state);
+            }
+            Answer::Text(text) => {
+                1u8.hash(state);
+                text.hash(state);
+            }
+            Answer::Image(image) => {
+                2u8.hash(state);
+                image.hash(state);
+            }
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::BigInteger(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Image(image) => write!(f, "{}", image),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::integer(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+    /// Panics if `n` doesn't fit in an `i128`
+    fn from(n: u128) -> Self {
+        Answer::integer(i128::try_from(n).expect("answer is too large"))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Image> for Answer {
+    fn from(image: Image) -> Self {
+        Answer::Image(image)
+    }
+}
+
+mod big_integer {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(n: &i128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(n)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i128, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// A rectangular picture of lit and unlit pixels, such as letters drawn with dots
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "Vec<String>", try_from = "Vec<String>")]
+pub struct Image {
+    rows: Vec<Vec<bool>>,
+}
+
+impl Image {
+    pub const LIT: char = '#';
+    pub const UNLIT: char = '.';
+
+    /// Panics if the rows aren't all the same length
+    pub fn new(rows: Vec<Vec<bool>>) -> Self {
+        assert!(
+            rows.windows(2).all(|pair| pair[0].len() == pair[1].len()),
+            "Image rows must all be the same length"
+        );
+        Image { rows }
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.len())
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn rows(&self) -> &[Vec<bool>] {
+        &self.rows
+    }
+
+    /// Whether the pixel at column `x` of row `y` is lit, or `None` if it's out of bounds
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        self.rows.get(y)?.get(x).copied()
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for &lit in row {
+                write!(f, "{}", if lit { Image::LIT } else { Image::UNLIT })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Image {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<Vec<bool>> = s
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| match c {
+                        Image::LIT => Ok(true),
+                        Image::UNLIT => Ok(false),
+                        c => Err(format!("unexpected pixel {:?}", c)),
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        if rows.windows(2).any(|pair| pair[0].len() != pair[1].len()) {
+            return Err("image rows must all be the same length".to_string());
+        }
+        Ok(Image { rows })
+    }
+}
+
+impl From<&Grid<bool>> for Image {
+    fn from(grid: &Grid<bool>) -> Self {
+        Image {
+            rows: grid.rows().map(<[bool]>::to_vec).collect(),
+        }
+    }
+}
+
+impl From<Image> for Vec<String> {
+    fn from(image: Image) -> Self {
+        image.to_string().lines().map(str::to_string).collect()
+    }
+}
+
+impl TryFrom<Vec<String>> for Image {
+    type Error = String;
+
+    fn try_from(rows: Vec<String>) -> Result<Self, Self::Error> {
+        rows.join("\n").parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_equality() {
+        assert_eq!(Answer::from(5u32), Answer::BigInteger(5));
+        assert_eq!(Answer::from(5usize), Answer::from(5u128));
+        assert_ne!(Answer::from(5), Answer::from("5"));
+        assert!(matches!(
+            Answer::from(u64::MAX),
+            Answer::BigInteger(n) if n == u64::MAX as i128
+        ));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(2984946368465u128).to_string(), "2984946368465");
+        assert_eq!(Answer::from("EFLFJGRF").to_string(), "EFLFJGRF");
+        let image: Image = "#.\n.#".parse().unwrap();
+        assert_eq!(Answer::from(image).to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn test_json() {
+        let answers = vec![
+            Answer::from(1502),
+            Answer::BigInteger(u64::MAX as i128 + 1),
+            Answer::from("EFLFJGRF"),
+            Answer::from("#.\n.#".parse::<Image>().unwrap()),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(
+            json,
+            r##"[{"integer":1502},{"big_integer":"18446744073709551616"},{"text":"EFLFJGRF"},{"image":["#.",".#"]}]"##
+        );
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_image() {
+        let image: Image = "##.\n..#".parse().unwrap();
+        assert_eq!((image.width(), image.height()), (3, 2));
+        assert_eq!(image.get(2, 1), Some(true));
+        assert_eq!(image.get(3, 0), None);
+        assert!("##\n#".parse::<Image>().is_err());
+        assert!("#x".parse::<Image>().is_err());
+    }
+}
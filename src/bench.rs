@@ -0,0 +1,185 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::timing::{Phase, Report, Stats};
+
+/// Where named baselines are kept, so `cargo clean` throws them away with the builds they timed
+pub const BASELINE_DIR: &str = "target/bench";
+
+/// Where the baseline called `name` is stored for `year`, or `None` if the name would escape
+/// the baseline directory
+pub fn baseline_path(root: &Path, year: u32, name: &str) -> Option<PathBuf> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    valid.then(|| root.join(year.to_string()).join(format!("{}.json", name)))
+}
+
+/// `None` if nothing has been saved under the name yet
+pub fn load(path: &Path) -> io::Result<Option<Report>> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    Report::from_json(&text)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+pub fn save(path: &Path, report: &Report) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, report.to_json())
+}
+
+/// Whether a phase got faster or slower between two baselines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Faster,
+    Slower,
+    /// The difference is within the noise
+    Unchanged,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = match self {
+            Verdict::Faster => "faster",
+            Verdict::Slower => "slower",
+            Verdict::Unchanged => "no change",
+        };
+        write!(f, "{}", verdict)
+    }
+}
+
+/// How one phase's mean time changed between two baselines
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub day: u32,
+    pub phase: Phase,
+    pub before: Stats,
+    pub after: Stats,
+    pub change_percent: f64,
+    pub verdict: Verdict,
+}
+
+/// Compare the mean time of every phase measured in both reports
+///
+/// A change only counts if the means differ by more than twice the standard error of their
+/// difference (Welch's t statistic over 2), which is roughly 95% confidence it isn't noise
+pub fn compare(baseline: &Report, current: &Report) -> Vec<Change> {
+    current
+        .measurements
+        .iter()
+        .filter_map(|m| {
+            let before = *baseline.get(m.day, m.phase)?;
+            let after = m.stats;
+            let difference = after.mean_ns as f64 - before.mean_ns as f64;
+            let change_percent = match before.mean_ns {
+                0 => 0.0,
+                mean => difference / mean as f64 * 100.0,
+            };
+            let verdict = if difference.abs() <= 2.0 * standard_error(&before, &after) {
+                Verdict::Unchanged
+            } else if difference < 0.0 {
+                Verdict::Faster
+            } else {
+                Verdict::Slower
+            };
+            Some(Change {
+                day: m.day,
+                phase: m.phase,
+                before,
+                after,
+                change_percent,
+                verdict,
+            })
+        })
+        .collect()
+}
+
+/// The standard error of the difference between two means
+fn standard_error(a: &Stats, b: &Stats) -> f64 {
+    let variance = |stats: &Stats| (stats.stddev_ns as f64).powi(2) / stats.runs.max(1) as f64;
+    (variance(a) + variance(b)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timing::Measurement;
+
+    fn report(means: &[(u32, Phase, u64, u64)]) -> Report {
+        Report {
+            measurements: means
+                .iter()
+                .map(|&(day, phase, mean_ns, stddev_ns)| Measurement {
+                    day,
+                    phase,
+                    stats: Stats {
+                        runs: 25,
+                        min_ns: mean_ns,
+                        median_ns: mean_ns,
+                        p95_ns: mean_ns,
+                        mean_ns,
+                        stddev_ns,
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_baseline_path() {
+        let root = Path::new("bench");
+        assert_eq!(
+            baseline_path(root, 2021, "before-astar"),
+            Some(PathBuf::from("bench/2021/before-astar.json"))
+        );
+        assert_eq!(baseline_path(root, 2021, ""), None);
+        assert_eq!(baseline_path(root, 2021, "../escape"), None);
+        assert_eq!(baseline_path(root, 2021, "a/b"), None);
+    }
+
+    #[test]
+    fn test_compare() {
+        let before = report(&[
+            (15, Phase::Part1, 1000, 50),
+            (15, Phase::Part2, 1000, 50),
+            (15, Phase::Parse, 1000, 500),
+        ]);
+        let after = report(&[
+            (15, Phase::Part1, 500, 50),
+            (15, Phase::Part2, 1100, 50),
+            // Slower, but too noisy to tell
+            (15, Phase::Parse, 1100, 500),
+            (16, Phase::Parse, 1, 0),
+        ]);
+        let changes = compare(&before, &after);
+        let verdicts: Vec<Verdict> = changes.iter().map(|c| c.verdict).collect();
+        assert_eq!(
+            verdicts,
+            vec![Verdict::Faster, Verdict::Slower, Verdict::Unchanged]
+        );
+        assert_eq!(changes[0].change_percent, -50.0);
+        assert_eq!(changes[1].change_percent, 10.0);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+        let path = baseline_path(&dir, 2021, "test").unwrap();
+        assert_eq!(load(&path).unwrap(), None);
+
+        let report = report(&[(1, Phase::Parse, 10, 1)]);
+        save(&path, &report).unwrap();
+        assert_eq!(load(&path).unwrap(), Some(report));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
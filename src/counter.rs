@@ -0,0 +1,239 @@
+use std::{
+    collections::{hash_map, HashMap},
+    hash::Hash,
+    iter::FromIterator,
+    ops::{Add, AddAssign, Mul, Sub, SubAssign},
+};
+
+/// A multiset: how many times each key has been seen
+///
+/// Keys which haven't been seen (or whose count drops to zero) aren't stored, so they're
+/// skipped when iterating
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<K: Hash + Eq> {
+    counts: HashMap<K, u64>,
+}
+
+impl<K: Hash + Eq> Default for Counter<K> {
+    fn default() -> Self {
+        Counter {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq> Counter<K> {
+    pub fn new() -> Self {
+        Counter::default()
+    }
+
+    pub fn add(&mut self, key: K) {
+        self.add_n(key, 1);
+    }
+
+    pub fn add_n(&mut self, key: K, n: u64) {
+        if n > 0 {
+            *self.counts.entry(key).or_insert(0) += n;
+        }
+    }
+
+    /// Remove up to `n` of `key`, returning how many were removed
+    pub fn remove_n(&mut self, key: &K, n: u64) -> u64 {
+        match self.counts.get_mut(key) {
+            Some(count) if *count > n => {
+                *count -= n;
+                n
+            }
+            Some(_) => self.counts.remove(key).unwrap_or(0),
+            None => 0,
+        }
+    }
+
+    /// How many times `key` has been seen, which may be zero
+    pub fn get(&self, key: &K) -> u64 {
+        self.counts.get(key).copied().unwrap_or(0)
+    }
+
+    /// The number of distinct keys
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The sum of every count
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    /// Every key with its count, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&K, u64)> {
+        self.counts.iter().map(|(key, &count)| (key, count))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.counts.keys()
+    }
+
+    /// Add every count in `other` to ours
+    pub fn merge(&mut self, other: Counter<K>) {
+        for (key, count) in other {
+            self.add_n(key, count);
+        }
+    }
+}
+
+impl<K: Hash + Eq + Ord> Counter<K> {
+    /// The key seen most often, with ties going to the smallest key
+    pub fn most_common(&self) -> Option<(&K, u64)> {
+        self.iter()
+            .min_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)))
+    }
+
+    /// The key seen least often, with ties going to the smallest key
+    pub fn least_common(&self) -> Option<(&K, u64)> {
+        self.iter()
+            .min_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(a.cmp(b)))
+    }
+
+    /// The `k` keys seen most often, most common first, with ties in key order
+    pub fn top_k(&self, k: usize) -> Vec<(&K, u64)> {
+        let mut counts: Vec<(&K, u64)> = self.iter().collect();
+        counts.sort_unstable_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        counts.truncate(k);
+        counts
+    }
+}
+
+impl<K: Hash + Eq> FromIterator<K> for Counter<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<K: Hash + Eq> Extend<K> for Counter<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.add(key);
+        }
+    }
+}
+
+impl<K: Hash + Eq, const N: usize> From<[(K, u64); N]> for Counter<K> {
+    fn from(counts: [(K, u64); N]) -> Self {
+        let mut counter = Counter::new();
+        for (key, count) in counts {
+            counter.add_n(key, count);
+        }
+        counter
+    }
+}
+
+impl<K: Hash + Eq> IntoIterator for Counter<K> {
+    type Item = (K, u64);
+    type IntoIter = hash_map::IntoIter<K, u64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+impl<K: Hash + Eq> AddAssign for Counter<K> {
+    fn add_assign(&mut self, other: Self) {
+        self.merge(other);
+    }
+}
+
+impl<K: Hash + Eq> Add for Counter<K> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+/// Subtracting saturates, so keys never go below zero
+impl<K: Hash + Eq> SubAssign for Counter<K> {
+    fn sub_assign(&mut self, other: Self) {
+        for (key, count) in other {
+            self.remove_n(&key, count);
+        }
+    }
+}
+
+impl<K: Hash + Eq> Sub for Counter<K> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+/// Scale every count
+impl<K: Hash + Eq> Mul<u64> for Counter<K> {
+    type Output = Self;
+
+    fn mul(self, n: u64) -> Self {
+        match n {
+            0 => Counter::new(),
+            n => Counter {
+                counts: self
+                    .counts
+                    .into_iter()
+                    .map(|(key, count)| (key, count * n))
+                    .collect(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_iter() {
+        let counter: Counter<char> = "abracadabra".chars().collect();
+        assert_eq!(counter.get(&'a'), 5);
+        assert_eq!(counter.get(&'z'), 0);
+        assert_eq!(counter.len(), 5);
+        assert_eq!(counter.total(), 11);
+    }
+
+    #[test]
+    fn test_most_and_least_common() {
+        let counter: Counter<char> = "abracadabra".chars().collect();
+        assert_eq!(counter.most_common(), Some((&'a', 5)));
+        // 'c' and 'd' both appear once
+        assert_eq!(counter.least_common(), Some((&'c', 1)));
+        assert_eq!(counter.top_k(3), vec![(&'a', 5), (&'b', 2), (&'r', 2)]);
+        assert_eq!(Counter::<char>::new().most_common(), None);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Counter::from([('x', 2), ('y', 1)]);
+        let b = Counter::from([('x', 1), ('z', 4)]);
+        assert_eq!(
+            a.clone() + b.clone(),
+            Counter::from([('x', 3), ('y', 1), ('z', 4)])
+        );
+        assert_eq!(a.clone() - b, Counter::from([('x', 1), ('y', 1)]));
+        assert_eq!(a * 3, Counter::from([('x', 6), ('y', 3)]));
+    }
+
+    #[test]
+    fn test_remove_n() {
+        let mut counter = Counter::from([('x', 2)]);
+        assert_eq!(counter.remove_n(&'x', 1), 1);
+        assert_eq!(counter.remove_n(&'x', 5), 1);
+        assert!(counter.is_empty());
+        assert_eq!(counter.remove_n(&'x', 1), 0);
+    }
+}
@@ -0,0 +1,50 @@
+use std::{error, fmt};
+
+use crate::{parse::ParseError, runner::Failure};
+
+/// Why `solve` couldn't produce an answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// There's no solution for this day
+    UnknownDay {
+        year: u32,
+        day: u32,
+    },
+    Parse(ParseError),
+    /// The solver panicked, with this message
+    Panic(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownDay { year, day } => write!(f, "no solution for {} day {}", year, day),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<Failure> for Error {
+    fn from(failure: Failure) -> Self {
+        match failure {
+            Failure::Parse(e) => Error::Parse(e),
+            Failure::Panic(message) => Error::Panic(message),
+        }
+    }
+}
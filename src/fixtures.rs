@@ -0,0 +1,213 @@
+use std::{
+    collections::BTreeSet,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Deserializer};
+
+use crate::{
+    registry::{self, Entry},
+    runner,
+    solution::{Params, Part},
+};
+
+/// Where example inputs live, in a directory per year alongside a `manifest.json` listing
+/// what each should be solved to
+pub const ROOT: &str = "fixtures";
+
+const MANIFEST: &str = "manifest.json";
+
+/// An example input, and the answers it should give
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Fixture {
+    pub day: u32,
+    /// The input's file name, relative to the year's directory
+    pub input: String,
+    #[serde(default)]
+    pub params: Params,
+    #[serde(default, deserialize_with = "expected")]
+    pub part1: Option<String>,
+    #[serde(default, deserialize_with = "expected")]
+    pub part2: Option<String>,
+}
+
+/// Expected answers can be written as JSON numbers or strings, but are compared as text
+fn expected<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Literal {
+        Integer(i64),
+        Text(String),
+    }
+
+    Ok(match Literal::deserialize(deserializer)? {
+        Literal::Integer(n) => Some(n.to_string()),
+        Literal::Text(text) => Some(text),
+    })
+}
+
+impl Fixture {
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+impl fmt::Display for Fixture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} ({}", self.day, self.input)?;
+        if !self.params.is_empty() {
+            write!(f, " with {}", self.params)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// The fixtures for one year, and the directory their inputs are in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifest {
+    pub dir: PathBuf,
+    pub fixtures: Vec<Fixture>,
+}
+
+impl Manifest {
+    pub fn load(root: &Path, year: u32) -> io::Result<Self> {
+        let dir = root.join(year.to_string());
+        let text = fs::read_to_string(dir.join(MANIFEST))?;
+        let fixtures = serde_json::from_str(&text)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Manifest { dir, fixtures })
+    }
+
+    /// Input files in the directory which no fixture uses
+    pub fn unused_inputs(&self) -> io::Result<Vec<String>> {
+        let used: BTreeSet<&str> = self.fixtures.iter().map(|f| f.input.as_str()).collect();
+        let mut unused = Vec::new();
+        for file in fs::read_dir(&self.dir)? {
+            let name = file?.file_name().to_string_lossy().into_owned();
+            if name != MANIFEST && !used.contains(name.as_str()) {
+                unused.push(name);
+            }
+        }
+        unused.sort();
+        Ok(unused)
+    }
+
+    /// Solve `fixture` with `year`'s solution, describing everything that went wrong
+    pub fn check(&self, year: u32, fixture: &Fixture) -> Vec<String> {
+        let Some(entry) = registry::get(year, fixture.day) else {
+            return vec!["no solution for this day".to_string()];
+        };
+        if fixture.part1.is_none() && fixture.part2.is_none() {
+            return vec!["no expected answers".to_string()];
+        }
+        let unknown: Vec<&str> = fixture
+            .params
+            .names()
+            .filter(|name| !entry.params.contains(name))
+            .collect();
+        if !unknown.is_empty() {
+            return vec![format!("unknown parameters {}", unknown.join(", "))];
+        }
+        let input = match fs::read_to_string(self.dir.join(&fixture.input)) {
+            Ok(input) => input,
+            Err(e) => return vec![format!("could not read input: {}", e)],
+        };
+        check_answers(entry, &input, fixture)
+    }
+}
+
+fn check_answers(entry: &Entry, input: &str, fixture: &Fixture) -> Vec<String> {
+    let parsed = match runner::catch_panic(|| entry.parse(input)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => return vec![e.to_string()],
+        Err(message) => return vec![format!("panicked while parsing: {}", message)],
+    };
+
+    Part::BOTH
+        .into_iter()
+        .filter_map(|part| {
+            let expected = fixture.expected(part)?;
+            match runner::catch_panic(|| entry.run_with(part, &parsed, &fixture.params)) {
+                Ok(answer) if answer.to_string() == expected => None,
+                Ok(answer) => Some(format!(
+                    "part {}: expected {:?}, got {:?}",
+                    part,
+                    expected,
+                    answer.to_string()
+                )),
+                Err(message) => Some(format!("part {} panicked: {}", part, message)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(ROOT)
+    }
+
+    #[test]
+    fn test_fixtures() {
+        let mut failures = Vec::new();
+        for year in registry::years() {
+            let manifest = Manifest::load(&root(), year.year).unwrap();
+            for fixture in &manifest.fixtures {
+                for problem in manifest.check(year.year, fixture) {
+                    failures.push(format!("{} {}: {}", year.year, fixture, problem));
+                }
+            }
+            for input in manifest.unused_inputs().unwrap() {
+                failures.push(format!("{} {}: not in the manifest", year.year, input));
+            }
+        }
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_every_day_has_a_fixture() {
+        for year in registry::years() {
+            let manifest = Manifest::load(&root(), year.year).unwrap();
+            for entry in year.days {
+                assert!(
+                    manifest.fixtures.iter().any(|f| f.day == entry.day),
+                    "{} day {} has no fixtures",
+                    year.year,
+                    entry.day
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_reports_problems() {
+        let manifest = Manifest::load(&root(), 2021).unwrap();
+        let fixture = Fixture {
+            day: 6,
+            input: "day6.txt".to_string(),
+            params: Params::from([("steps", 1)]),
+            part1: Some("26".to_string()),
+            part2: None,
+        };
+        assert_eq!(
+            manifest.check(2021, &fixture),
+            vec!["unknown parameters steps"]
+        );
+
+        let fixture = Fixture {
+            params: Params::from([("days", 18)]),
+            part1: Some("27".to_string()),
+            ..fixture
+        };
+        assert_eq!(
+            manifest.check(2021, &fixture),
+            vec![r#"part 1: expected "27", got "26""#]
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{
+    parse::{self, ParseError},
+    point::Point2,
+};
+
+/// A position in a grid, as `(x, y)`: the column, then the row
+pub type Position = (usize, usize);
+
+/// A rectangular grid, stored row by row in one `Vec`
+///
+/// Indexing with `grid[(x, y)]` panics when out of bounds, `get` doesn't
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Build a grid by calling `f` with each position, row by row
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// `None` if the rows aren't all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.offset(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.offset(position).map(|i| &mut self.cells[i])
+    }
+
+    fn offset(&self, (x, y): Position) -> Option<usize> {
+        self.contains((x, y)).then(|| y * self.width + x)
+    }
+
+    /// The bottom-right position, or `None` if the grid is empty
+    pub fn last_position(&self) -> Option<Position> {
+        (!self.is_empty()).then(|| (self.width - 1, self.height - 1))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` won't take a size of 0
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The positions above, right of, below and left of `position` which are in the grid
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &Point2::DIRECTIONS4)
+    }
+
+    /// As `neighbours4`, plus the diagonals
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &Point2::DIRECTIONS8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        position: Position,
+        directions: &'static [Point2],
+    ) -> impl Iterator<Item = Position> + 'a {
+        let point = Point2::from(position);
+        directions.iter().filter_map(move |&direction| {
+            let neighbour = Position::try_from(point + direction).ok()?;
+            self.contains(neighbour).then_some(neighbour)
+        })
+    }
+
+    /// The cell at `point`, which may be off the grid in any direction
+    pub fn get_point(&self, point: Point2) -> Option<&T> {
+        self.get(Position::try_from(point).ok()?)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swap rows and columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Mirror left to right
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |(x, y)| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirror top to bottom
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |(x, y)| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+
+    /// Parse a non-empty rectangle of characters, each turned into a cell by `symbol` (or
+    /// rejected, if it returns `None`)
+    pub fn parse(
+        day: u32,
+        input: &str,
+        mut symbol: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in parse::lines(day, input) {
+            let mut row_width = 0;
+            for (i, c) in line.text.chars().enumerate() {
+                let cell = symbol(c).ok_or_else(|| {
+                    line.error_at_column(i + 1, format!("unexpected character {:?}", c))
+                })?;
+                cells.push(cell);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(line.error(format!(
+                        "expected {} characters per row, found {}",
+                        width, row_width
+                    )))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(parse::unexpected_end(day, input, "expected a grid")),
+        }
+    }
+}
+
+impl Grid<u32> {
+    /// Parse a non-empty rectangle of digits
+    pub fn parse_digits(day: u32, input: &str) -> Result<Self, ParseError> {
+        Grid::parse(day, input, |c| c.to_digit(10))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside a {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside a {}x{} grid", position, width, height),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn example() -> Grid<u32> {
+        Grid::parse_digits(1, "123\n456").unwrap()
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+
+        let error = Grid::parse_digits(1, "12\n3x").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(Grid::parse_digits(1, "12\n345").unwrap_err().line, 2);
+        assert_eq!(Grid::parse_digits(1, "").unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn test_parse_symbols() {
+        let grid = Grid::parse(1, "#.\n.#", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        });
+        assert_eq!(
+            grid,
+            Ok(Grid::from_rows(vec![vec![true, false], vec![false, true]]).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = example();
+        let rows: Vec<&[u32]> = grid.rows().collect();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+        let columns: Vec<Vec<u32>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(10, 10, 0);
+        assert_eq!(
+            grid.neighbours8((2, 0)).collect::<HashSet<_>>(),
+            HashSet::from([(1, 0), (3, 0), (1, 1), (2, 1), (3, 1)])
+        );
+        assert_eq!(
+            grid.neighbours4((0, 9)).collect::<HashSet<_>>(),
+            HashSet::from([(0, 8), (1, 9)])
+        );
+    }
+
+    #[test]
+    fn test_transpose_and_flip() {
+        let grid = example();
+        assert_eq!(
+            grid.transpose(),
+            Grid::parse_digits(1, "14\n25\n36").unwrap()
+        );
+        assert_eq!(
+            grid.flip_horizontal(),
+            Grid::parse_digits(1, "321\n654").unwrap()
+        );
+        assert_eq!(
+            grid.flip_vertical(),
+            Grid::parse_digits(1, "456\n123").unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 2) is outside a 3x2 grid")]
+    fn test_index_out_of_bounds() {
+        let _ = example()[(0, 2)];
+    }
+}
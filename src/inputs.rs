@@ -0,0 +1,91 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// One input for a day, named after the account it belongs to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedInput {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// Where one year's inputs live, e.g. `input/2021`
+pub fn root(year: u32) -> PathBuf {
+    Path::new("input").join(year.to_string())
+}
+
+/// The single-file input for `day`, e.g. `input/2021/day5.txt`
+pub fn default_path(root: &Path, day: u32) -> PathBuf {
+    root.join(format!("day{}.txt", day))
+}
+
+/// An account's input for `day`, e.g. `input/2021/day5/alice.txt`
+pub fn named_path(root: &Path, day: u32, name: &str) -> PathBuf {
+    root.join(format!("day{}", day))
+        .join(format!("{}.txt", name))
+}
+
+/// Find every input for `day`: the single-file layout (named after the day), followed by each
+/// `.txt` file in the day's directory in name order
+pub fn discover(root: &Path, day: u32) -> io::Result<Vec<NamedInput>> {
+    let mut inputs = Vec::new();
+
+    let single = default_path(root, day);
+    if single.is_file() {
+        inputs.push(NamedInput {
+            name: format!("day{}", day),
+            path: single,
+        });
+    }
+
+    let directory = root.join(format!("day{}", day));
+    if directory.is_dir() {
+        let mut named = Vec::new();
+        for entry in fs::read_dir(&directory)? {
+            let path = entry?.path();
+            if !path.is_file() || path.extension().is_none_or(|extension| extension != "txt") {
+                continue;
+            }
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                named.push(NamedInput {
+                    name: name.to_string(),
+                    path,
+                });
+            }
+        }
+        named.sort_by(|a, b| a.name.cmp(&b.name));
+        inputs.extend(named);
+    }
+
+    Ok(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discover() {
+        let root = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(root.join("day3")).unwrap();
+        fs::write(root.join("day3.txt"), "").unwrap();
+        fs::write(root.join("day3").join("zed.txt"), "").unwrap();
+        fs::write(root.join("day3").join("alice.txt"), "").unwrap();
+        fs::write(root.join("day3").join("notes.md"), "").unwrap();
+
+        let names: Vec<String> = discover(&root, 3)
+            .unwrap()
+            .into_iter()
+            .map(|input| input.name)
+            .collect();
+        assert_eq!(names, vec!["day3", "alice", "zed"]);
+        assert_eq!(
+            discover(&root, 3).unwrap()[1].path,
+            named_path(&root, 3, "alice")
+        );
+        assert_eq!(discover(&root, 4).unwrap(), vec![]);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
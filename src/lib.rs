@@ -1,22 +1,25 @@
-extern crate aoc_runner;
+pub mod answer;
+pub mod bench;
+pub mod counter;
+pub mod error;
+pub mod fixtures;
+pub mod grid;
+pub mod inputs;
+pub mod memo;
+pub mod normalize;
+pub mod ocr;
+pub mod parse;
+pub mod pathfinding;
+pub mod point;
+pub mod registry;
+pub mod runner;
+pub mod solution;
+pub mod timing;
+pub mod verify;
+pub mod y2021;
 
-#[macro_use]
-extern crate aoc_runner_derive;
-
-pub mod day1;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-
-aoc_lib! { year = 2021 }
+pub use answer::Answer;
+pub use error::Error;
+pub use parse::ParseError;
+pub use solution::{Params, Part, Solution};
+pub use y2021::solve;
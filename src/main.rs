@@ -1,7 +1,757 @@
-extern crate advent_of_code_2021;
-extern crate aoc_runner_derive;
-extern crate aoc_runner;
+use std::{
+    fs,
+    io::{self, Read},
+    panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, Instant},
+};
 
-use aoc_runner_derive::aoc_main;
+use advent_of_code_2021::{
+    bench::{self, Verdict},
+    inputs,
+    registry::{self, Entry, Year},
+    runner::{self, DayResult, Failure, Solved},
+    timing::{self, Report},
+    verify::{Answers, Expected, Outcome},
+    Answer, Part,
+};
+use clap::{builder::RangedU64ValueParser, Args, Parser, Subcommand, ValueEnum};
 
-aoc_main! { lib = advent_of_code_2021 }
+/// Exit codes, so scripts can tell bad input apart from a broken solver
+/// (2 is also used by clap for invalid arguments)
+const EXIT_IO_ERROR: u8 = 1;
+const EXIT_USAGE: u8 = 2;
+const EXIT_PARSE_ERROR: u8 = 3;
+const EXIT_PANIC: u8 = 4;
+const EXIT_INCORRECT: u8 = 5;
+const EXIT_REGRESSION: u8 = 6;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    /// Event year to solve
+    #[arg(short, long, global = true, default_value_t = registry::LATEST_YEAR)]
+    year: u32,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day (or every day) and print the answers
+    Run(RunArgs),
+    /// Solve each day for every input we have, and print a table of answers per input
+    Batch(BatchArgs),
+    /// Check the answers for our inputs haven't changed
+    Verify(VerifyArgs),
+    /// Time each phase of each day over repeated runs
+    Time(TimeArgs),
+    /// Benchmark each day, save the results as a named baseline and compare with another
+    Bench(BenchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u32>,
+
+    /// Solve every day
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+
+    /// Only solve one part
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    /// Input file, or `-` for stdin [default: input/YEAR/dayN.txt]
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Use a named account's input from input/YEAR/dayN/<NAME>.txt
+    #[arg(short, long, conflicts_with = "input")]
+    name: Option<String>,
+
+    /// Only print the answers, one per line
+    #[arg(short, long)]
+    quiet: bool,
+
+    /// Solve days and parts concurrently on up to N threads [default N: one per core]
+    #[arg(short, long, value_name = "N", value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    jobs: Option<Option<usize>>,
+}
+
+#[derive(Args)]
+struct BatchArgs {
+    /// Only solve one day
+    #[arg(short, long)]
+    day: Option<u32>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify one day
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// File of expected answers [default: answers/YEAR.txt]
+    #[arg(short, long)]
+    answers: Option<PathBuf>,
+
+    /// Add any newly solved parts to the answers file
+    #[arg(long)]
+    record: bool,
+
+    /// Record new answers as salted hashes, rather than in plain text
+    #[arg(long, requires = "record")]
+    hash: bool,
+
+    /// Solve days and parts concurrently on up to N threads [default N: one per core]
+    #[arg(short, long, value_name = "N", value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    jobs: Option<Option<usize>>,
+}
+
+#[derive(Args)]
+struct TimeArgs {
+    /// Only time one day
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// How many times to run each phase
+    #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+
+    /// How many untimed runs to do first
+    #[arg(short, long, default_value_t = 0)]
+    warmup: u64,
+
+    /// How to print the report
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Also save the report, as CSV if the file ends in `.csv` and JSON otherwise
+    #[arg(short, long)]
+    save: Option<PathBuf>,
+
+    /// A previously saved report to compare against
+    #[arg(short, long)]
+    compare: Option<PathBuf>,
+
+    /// Percentage slowdown in a phase's median time to flag as a regression
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark one day
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// How many times to run each phase
+    #[arg(short, long, default_value_t = 30, value_parser = clap::value_parser!(u64).range(2..))]
+    runs: u64,
+
+    /// How many untimed runs to do first
+    #[arg(short, long, default_value_t = 3)]
+    warmup: u64,
+
+    /// Name to save the results under, replacing any earlier results with that name
+    #[arg(short, long, default_value = "latest")]
+    save: String,
+
+    /// Baseline to compare with [default: whatever was last saved under the same name]
+    #[arg(short, long)]
+    compare: Option<String>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+fn read_input(year: u32, day: u32, path: Option<&PathBuf>) -> io::Result<String> {
+    match path {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path),
+        None => fs::read_to_string(inputs::default_path(&inputs::root(year), day)),
+    }
+}
+
+/// Look up one day, or every day of the year if none was given
+fn select_days(year: &Year, day: Option<u32>) -> Result<Vec<&'static Entry>, ExitCode> {
+    match day {
+        Some(day) => match year.get(day) {
+            Some(entry) => Ok(vec![entry]),
+            None => {
+                eprintln!("Day {} of {} hasn't been solved", day, year.year);
+                Err(ExitCode::from(EXIT_USAGE))
+            }
+        },
+        None => Ok(year.days.iter().collect()),
+    }
+}
+
+fn exit_code(failure: &Failure) -> u8 {
+    match failure {
+        Failure::Parse(_) => EXIT_PARSE_ERROR,
+        Failure::Panic(_) => EXIT_PANIC,
+    }
+}
+
+/// How many threads `--jobs` asked for, if any
+fn threads(jobs: Option<Option<usize>>) -> Option<usize> {
+    jobs.map(|threads| {
+        threads.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+    })
+}
+
+/// Solve each day in turn, or concurrently on up to `threads` threads, saying how many were used
+fn solve_days(
+    days: &[(&Entry, &str)],
+    parts: &[Part],
+    threads: Option<usize>,
+) -> (Vec<DayResult>, Option<usize>) {
+    match threads {
+        Some(threads) => {
+            let (results, used) = runner::solve_parallel(days, parts, threads);
+            (results, Some(used))
+        }
+        None => {
+            let results = days
+                .iter()
+                .map(|&(entry, input)| runner::solve(entry, input, parts))
+                .collect();
+            (results, None)
+        }
+    }
+}
+
+fn run(year: &Year, args: &RunArgs) -> ExitCode {
+    let entries = match select_days(year, args.day) {
+        Ok(entries) => entries,
+        Err(code) => return code,
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+
+    let mut code = 0;
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let path = args
+            .name
+            .as_ref()
+            .map(|name| inputs::named_path(&inputs::root(year.year), entry.day, name));
+        match read_input(year.year, entry.day, path.as_ref().or(args.input.as_ref())) {
+            Ok(input) => inputs.push((entry, input)),
+            Err(e) => {
+                eprintln!("Day {}: could not read input: {}", entry.day, e);
+                code = code.max(EXIT_IO_ERROR);
+            }
+        }
+    }
+    let days: Vec<(&Entry, &str)> = inputs
+        .iter()
+        .map(|(entry, input)| (*entry, input.as_str()))
+        .collect();
+
+    let start = Instant::now();
+    let (results, threads) = solve_days(&days, &parts, threads(args.jobs));
+    let wall_time = start.elapsed();
+
+    for result in &results {
+        let day = result.day;
+        if !result.changes.is_empty() {
+            eprintln!("Day {}: normalized input: {}", day, result.changes);
+        }
+        let solved = match &result.parts {
+            Ok(solved) => solved,
+            Err(failure) => {
+                eprintln!("Day {}: {}", day, failure);
+                code = code.max(exit_code(failure));
+                continue;
+            }
+        };
+
+        for Solved {
+            part,
+            answer,
+            elapsed,
+        } in solved
+        {
+            match answer {
+                Ok(answer) if args.quiet => println!("{}", answer),
+                // Images go on the lines below, so their rows line up
+                Ok(Answer::Image(image)) => println!(
+                    "Day {} - Part {}: (parse: {:?}, solve: {:?})\n{}",
+                    day, part, result.parse_time, elapsed, image
+                ),
+                Ok(answer) => println!(
+                    "Day {} - Part {}: {} (parse: {:?}, solve: {:?})",
+                    day, part, answer, result.parse_time, elapsed
+                ),
+                Err(failure) => {
+                    eprintln!("Day {} - Part {}: {}", day, part, failure);
+                    code = code.max(exit_code(failure));
+                }
+            }
+        }
+    }
+
+    if let (Some(threads), false) = (threads, args.quiet) {
+        println!();
+        for result in &results {
+            println!("Day {:>2}: {:>12?}", result.day, result.total_time());
+        }
+        let total: Duration = results.iter().map(DayResult::total_time).sum();
+        println!(
+            "Total:  {:>12?} on {} ({:?} one after another)",
+            wall_time,
+            match threads {
+                1 => "1 thread".to_string(),
+                threads => format!("{} threads", threads),
+            },
+            total
+        );
+    }
+    ExitCode::from(code)
+}
+
+/// Render an answer (or failure) so it fits in one table cell
+fn cell(answer: Result<&Answer, &Failure>) -> String {
+    match answer {
+        Ok(answer) => answer.to_string().replace('\n', " "),
+        Err(Failure::Parse(e)) => format!("parse error on line {}", e.line),
+        Err(Failure::Panic(message)) => format!("panicked: {}", message),
+    }
+}
+
+fn batch(year: &Year, args: &BatchArgs) -> ExitCode {
+    let entries = match select_days(year, args.day) {
+        Ok(entries) => entries,
+        Err(code) => return code,
+    };
+
+    let mut code = 0;
+    for entry in entries {
+        let named_inputs = match inputs::discover(&inputs::root(year.year), entry.day) {
+            Ok(named_inputs) if named_inputs.is_empty() => {
+                eprintln!("Day {}: no inputs found", entry.day);
+                code = code.max(EXIT_IO_ERROR);
+                continue;
+            }
+            Ok(named_inputs) => named_inputs,
+            Err(e) => {
+                eprintln!("Day {}: could not list inputs: {}", entry.day, e);
+                code = code.max(EXIT_IO_ERROR);
+                continue;
+            }
+        };
+
+        let mut rows = vec![[
+            "input".to_string(),
+            "part 1".to_string(),
+            "part 2".to_string(),
+        ]];
+        for named in named_inputs {
+            let [part1, part2] = match fs::read_to_string(&named.path) {
+                Ok(input) => {
+                    let result = runner::solve(entry, &input, &Part::BOTH);
+                    Part::BOTH.map(|part| match result.answer(part) {
+                        Some(answer) => {
+                            if let Err(failure) = answer {
+                                code = code.max(exit_code(failure));
+                            }
+                            cell(answer)
+                        }
+                        None => String::new(),
+                    })
+                }
+                Err(e) => {
+                    code = code.max(EXIT_IO_ERROR);
+                    let message = format!("could not read input: {}", e);
+                    [message.clone(), message]
+                }
+            };
+            rows.push([named.name, part1, part2]);
+        }
+
+        let widths: Vec<usize> = (0..3)
+            .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+            .collect();
+        println!("Day {}: {}", entry.day, entry.title);
+        for row in rows {
+            println!(
+                "  {:<w0$}  {:<w1$}  {}",
+                row[0],
+                row[1],
+                row[2],
+                w0 = widths[0],
+                w1 = widths[1]
+            );
+        }
+        println!();
+    }
+    ExitCode::from(code)
+}
+
+fn verify(year: &Year, args: &VerifyArgs) -> ExitCode {
+    let entries = match select_days(year, args.day) {
+        Ok(entries) => entries,
+        Err(code) => return code,
+    };
+
+    let path = args
+        .answers
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(format!("answers/{}.txt", year.year)));
+    let mut answers: Answers = match fs::read_to_string(&path) {
+        Ok(text) => match text.parse() {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{}: {}", path.display(), e);
+                return ExitCode::from(EXIT_PARSE_ERROR);
+            }
+        },
+        // Recording into a new file is fine, checking against nothing isn't
+        Err(e) if e.kind() == io::ErrorKind::NotFound && args.record => Answers::default(),
+        Err(e) => {
+            eprintln!("Could not read {}: {}", path.display(), e);
+            return ExitCode::from(EXIT_IO_ERROR);
+        }
+    };
+
+    let inputs: Vec<(&Entry, io::Result<String>)> = entries
+        .iter()
+        .map(|&entry| (entry, read_input(year.year, entry.day, None)))
+        .collect();
+    let days: Vec<(&Entry, &str)> = inputs
+        .iter()
+        .filter_map(|(entry, input)| Some((*entry, input.as_deref().ok()?)))
+        .collect();
+    let mut results = solve_days(&days, &Part::BOTH, threads(args.jobs))
+        .0
+        .into_iter();
+
+    let mut outcomes: Vec<(u32, Part, Outcome)> = Vec::new();
+    for (entry, input) in &inputs {
+        let result = input
+            .as_ref()
+            .map(|_| results.next().expect("every input read was solved"));
+        for part in Part::BOTH {
+            let answer = match &result {
+                Ok(result) => match result.answer(part) {
+                    Some(Ok(answer)) => Ok(answer.to_string()),
+                    Some(Err(failure)) => Err(failure.to_string()),
+                    None => Err("not solved".to_string()),
+                },
+                Err(e) => Err(format!("could not read input: {}", e)),
+            };
+            let outcome = answers.check(entry.day, part, answer.as_deref().map_err(|e| e.as_str()));
+            outcomes.push((entry.day, part, outcome));
+        }
+    }
+
+    // Answers we expect, but for days we haven't got a solution for any more
+    for (day, part, _) in answers.iter() {
+        if args.day.is_none_or(|d| d == day) && year.get(day).is_none() {
+            outcomes.push((
+                day,
+                part,
+                Outcome::Missing {
+                    reason: "no solution for this day".to_string(),
+                },
+            ));
+        }
+    }
+
+    let mut recorded = 0;
+    for (day, part, outcome) in &outcomes {
+        println!("Day {} - Part {}: {}", day, part, outcome);
+        if let (true, Outcome::New { actual }) = (args.record, outcome) {
+            let expected = match args.hash {
+                true => Expected::hashed(actual),
+                false => Expected::Plain(actual.clone()),
+            };
+            answers.insert(*day, *part, expected);
+            recorded += 1;
+        }
+    }
+
+    let count = |f: fn(&Outcome) -> bool| outcomes.iter().filter(|(_, _, o)| f(o)).count();
+    println!(
+        "{} correct, {} incorrect, {} missing, {} new",
+        count(|o| matches!(o, Outcome::Correct)),
+        count(|o| matches!(o, Outcome::Incorrect { .. })),
+        count(|o| matches!(o, Outcome::Missing { .. })),
+        count(|o| matches!(o, Outcome::New { .. })),
+    );
+
+    if recorded > 0 {
+        if let Err(e) = fs::write(&path, answers.to_string()) {
+            eprintln!("Could not write {}: {}", path.display(), e);
+            return ExitCode::from(EXIT_IO_ERROR);
+        }
+        println!("Recorded {} new answers in {}", recorded, path.display());
+    }
+
+    if outcomes.iter().any(|(_, _, outcome)| outcome.is_failure()) {
+        ExitCode::from(EXIT_INCORRECT)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn is_csv(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "csv")
+}
+
+fn time(year: &Year, args: &TimeArgs) -> ExitCode {
+    let entries = match select_days(year, args.day) {
+        Ok(entries) => entries,
+        Err(code) => return code,
+    };
+
+    // Load the baseline first, so we don't spend time measuring only to fail
+    let baseline = match &args.compare {
+        Some(path) => {
+            let report = fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|text| match is_csv(path) {
+                    true => Report::from_csv(&text).map_err(|e| e.to_string()),
+                    false => Report::from_json(&text).map_err(|e| e.to_string()),
+                });
+            match report {
+                Ok(report) => Some(report),
+                Err(e) => {
+                    eprintln!("Could not load {}: {}", path.display(), e);
+                    return ExitCode::from(EXIT_IO_ERROR);
+                }
+            }
+        }
+        None => None,
+    };
+
+    let mut code = 0;
+    let mut report = Report::default();
+    for entry in entries {
+        let input = match read_input(year.year, entry.day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {}: could not read input: {}", entry.day, e);
+                code = code.max(EXIT_IO_ERROR);
+                continue;
+            }
+        };
+        match timing::measure(entry, &input, args.warmup as usize, args.runs as usize) {
+            Ok(measurements) => report.measurements.extend(measurements),
+            Err(failure) => {
+                eprintln!("Day {}: {}", entry.day, failure);
+                code = code.max(exit_code(&failure));
+            }
+        }
+    }
+
+    match args.format {
+        Format::Json => println!("{}", report.to_json()),
+        Format::Csv => print!("{}", report.to_csv()),
+        Format::Table => {
+            println!(
+                "{:>3}  {:<6} {:>5} {:>12} {:>12} {:>12}",
+                "day", "phase", "runs", "min", "median", "p95"
+            );
+            for m in &report.measurements {
+                println!(
+                    "{:>3}  {:<6} {:>5} {:>12?} {:>12?} {:>12?}",
+                    m.day,
+                    m.phase,
+                    m.stats.runs,
+                    Duration::from_nanos(m.stats.min_ns),
+                    Duration::from_nanos(m.stats.median_ns),
+                    Duration::from_nanos(m.stats.p95_ns),
+                );
+            }
+        }
+    }
+
+    if let Some(path) = &args.save {
+        let text = match is_csv(path) {
+            true => report.to_csv(),
+            false => report.to_json(),
+        };
+        if let Err(e) = fs::write(path, text) {
+            eprintln!("Could not write {}: {}", path.display(), e);
+            code = code.max(EXIT_IO_ERROR);
+        }
+    }
+
+    if let Some(baseline) = baseline {
+        // Keep stdout parseable when it's JSON or CSV
+        for comparison in timing::compare(&baseline, &report, args.threshold) {
+            eprintln!(
+                "Day {:>2} {:<6} {:>12?} -> {:>12?} ({:+.1}%){}",
+                comparison.day,
+                comparison.phase,
+                Duration::from_nanos(comparison.before_ns),
+                Duration::from_nanos(comparison.after_ns),
+                comparison.change_percent,
+                if comparison.regressed {
+                    "  REGRESSION"
+                } else {
+                    ""
+                }
+            );
+            if comparison.regressed {
+                code = code.max(EXIT_REGRESSION);
+            }
+        }
+    }
+
+    ExitCode::from(code)
+}
+
+fn bench(year: &Year, args: &BenchArgs) -> ExitCode {
+    let entries = match select_days(year, args.day) {
+        Ok(entries) => entries,
+        Err(code) => return code,
+    };
+
+    let root = Path::new(bench::BASELINE_DIR);
+    let baseline_path = |name: &str| {
+        let path = bench::baseline_path(root, year.year, name);
+        if path.is_none() {
+            eprintln!(
+                "Invalid baseline name {:?}: use letters, digits, `-`, `_` and `.`",
+                name
+            );
+        }
+        path
+    };
+    let Some(save_path) = baseline_path(&args.save) else {
+        return ExitCode::from(EXIT_USAGE);
+    };
+    let compare_name = args.compare.as_ref().unwrap_or(&args.save);
+    let Some(compare_path) = baseline_path(compare_name) else {
+        return ExitCode::from(EXIT_USAGE);
+    };
+
+    // Load the baseline before saving over it
+    let baseline = match bench::load(&compare_path) {
+        Ok(Some(baseline)) => Some(baseline),
+        // Only an error if it was asked for by name
+        Ok(None) if args.compare.is_none() => None,
+        Ok(None) => {
+            eprintln!("No baseline called {:?} for {}", compare_name, year.year);
+            return ExitCode::from(EXIT_USAGE);
+        }
+        Err(e) => {
+            eprintln!("Could not load {}: {}", compare_path.display(), e);
+            return ExitCode::from(EXIT_IO_ERROR);
+        }
+    };
+
+    let mut code = 0;
+    let mut report = Report::default();
+    println!(
+        "{:>3}  {:<6} {:>5} {:>12} {:>12} {:>12}",
+        "day", "phase", "runs", "mean", "stddev", "median"
+    );
+    for entry in entries {
+        let input = match read_input(year.year, entry.day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {}: could not read input: {}", entry.day, e);
+                code = code.max(EXIT_IO_ERROR);
+                continue;
+            }
+        };
+        let measurements =
+            match timing::measure(entry, &input, args.warmup as usize, args.runs as usize) {
+                Ok(measurements) => measurements,
+                Err(failure) => {
+                    eprintln!("Day {}: {}", entry.day, failure);
+                    code = code.max(exit_code(&failure));
+                    continue;
+                }
+            };
+        for m in &measurements {
+            println!(
+                "{:>3}  {:<6} {:>5} {:>12?} {:>12?} {:>12?}",
+                m.day,
+                m.phase,
+                m.stats.runs,
+                Duration::from_nanos(m.stats.mean_ns),
+                Duration::from_nanos(m.stats.stddev_ns),
+                Duration::from_nanos(m.stats.median_ns),
+            );
+        }
+        report.measurements.extend(measurements);
+    }
+
+    if let Some(baseline) = baseline {
+        println!();
+        println!("Compared with {:?}:", compare_name);
+        for change in bench::compare(&baseline, &report) {
+            println!(
+                "Day {:>2} {:<6} {:>12?} -> {:>12?} ({:+.1}%)  {}",
+                change.day,
+                change.phase,
+                Duration::from_nanos(change.before.mean_ns),
+                Duration::from_nanos(change.after.mean_ns),
+                change.change_percent,
+                match change.verdict {
+                    Verdict::Unchanged => "no change (within noise)".to_string(),
+                    verdict => verdict.to_string(),
+                }
+            );
+        }
+    }
+
+    if let Err(e) = bench::save(&save_path, &report) {
+        eprintln!("Could not write {}: {}", save_path.display(), e);
+        code = code.max(EXIT_IO_ERROR);
+    } else {
+        println!("Saved as {:?} in {}", args.save, save_path.display());
+    }
+
+    ExitCode::from(code)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    // Panics are reported by `catch_panic`, so don't print them twice
+    panic::set_hook(Box::new(|_| {}));
+
+    let Some(year) = registry::year(cli.year) else {
+        let years: Vec<String> = registry::years()
+            .iter()
+            .map(|y| y.year.to_string())
+            .collect();
+        eprintln!(
+            "No solutions for {} (solved years: {})",
+            cli.year,
+            years.join(", ")
+        );
+        return ExitCode::from(EXIT_USAGE);
+    };
+
+    match cli.command {
+        Command::Run(args) => run(year, &args),
+        Command::Batch(args) => batch(year, &args),
+        Command::Verify(args) => verify(year, &args),
+        Command::Time(args) => time(year, &args),
+        Command::Bench(args) => bench(year, &args),
+    }
+}
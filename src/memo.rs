@@ -0,0 +1,165 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A table of results already computed, owned by whoever is solving so it's dropped (along
+/// with its memory) once they're done
+///
+/// With a capacity limit, results past the limit are still returned but not stored
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    capacity: Option<usize>,
+    stats: MemoStats,
+}
+
+/// How well a memo table has been doing
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoStats {
+    /// Lookups which found a stored result
+    pub hits: u64,
+    /// Lookups which didn't
+    pub misses: u64,
+    /// Results we couldn't store because the table was full
+    pub rejected: u64,
+}
+
+impl MemoStats {
+    /// The fraction of lookups which were hits, or 0 if there haven't been any
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            values: HashMap::new(),
+            capacity: None,
+            stats: MemoStats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Memo<K, V> {
+    /// A table without a capacity limit
+    pub fn new() -> Self {
+        Memo::default()
+    }
+
+    /// A table which stores at most `capacity` results
+    pub fn with_capacity_limit(capacity: usize) -> Self {
+        Memo {
+            capacity: Some(capacity),
+            ..Memo::default()
+        }
+    }
+
+    /// Look up a stored result, counting a hit or miss
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let value = self.values.get(key);
+        match value {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        value
+    }
+
+    /// Store a result, unless the table is full (in which case it's dropped)
+    pub fn insert(&mut self, key: K, value: V) {
+        if self.capacity.is_some_and(|capacity| {
+            self.values.len() >= capacity && !self.values.contains_key(&key)
+        }) {
+            self.stats.rejected += 1;
+            return;
+        }
+        self.values.insert(key, value);
+    }
+
+    /// The stored result for `key`, computing (and storing) it with `f` on a miss
+    ///
+    /// Recursive functions can't use this, as `f` can't borrow the table, so should `get`
+    /// then `insert` instead
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce() -> V) -> V
+    where
+        V: Clone,
+    {
+        if let Some(value) = self.get(&key) {
+            return value.clone();
+        }
+        let value = f();
+        self.insert(key, value.clone());
+        value
+    }
+
+    /// The number of results stored
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    /// Forget every result and reset the statistics
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.stats = MemoStats::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        if let Some(&value) = memo.get(&n) {
+            return value;
+        }
+        let value = fibonacci(memo, n - 1) + fibonacci(memo, n - 2);
+        memo.insert(n, value);
+        value
+    }
+
+    #[test]
+    fn test_recursive() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+        assert_eq!(memo.len(), 89);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 87,
+                misses: 89,
+                rejected: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_capacity_limit() {
+        let mut memo = Memo::with_capacity_limit(10);
+        assert_eq!(fibonacci(&mut memo, 40), 102334155);
+        assert_eq!(memo.len(), 10);
+        assert!(memo.stats().rejected > 0);
+    }
+
+    #[test]
+    fn test_get_or_insert_with() {
+        let mut memo = Memo::new();
+        assert_eq!(memo.get_or_insert_with(3, || 9), 9);
+        assert_eq!(memo.get_or_insert_with(3, || unreachable!()), 9);
+        assert_eq!(memo.stats().hit_rate(), 0.5);
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats(), MemoStats::default());
+    }
+}
@@ -0,0 +1,162 @@
+use std::{borrow::Cow, fmt};
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+/// Everything `normalize` had to fix
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Changes {
+    pub byte_order_mark: bool,
+    /// `\r\n` and lone `\r` line endings, now `\n`
+    pub line_endings: usize,
+    /// Lines which had whitespace at the end
+    pub trailing_whitespace: usize,
+    /// Blank lines after the last line of text
+    pub trailing_blank_lines: usize,
+}
+
+impl Changes {
+    pub fn is_empty(&self) -> bool {
+        *self == Changes::default()
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    match count {
+        1 => format!("1 {}", noun),
+        _ => format!("{} {}s", count, noun),
+    }
+}
+
+impl fmt::Display for Changes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut changes = Vec::new();
+        if self.byte_order_mark {
+            changes.push("removed a byte order mark".to_string());
+        }
+        if self.line_endings > 0 {
+            changes.push(format!(
+                "converted {} to \\n",
+                plural(self.line_endings, "line ending")
+            ));
+        }
+        if self.trailing_whitespace > 0 {
+            changes.push(format!(
+                "trimmed trailing whitespace from {}",
+                plural(self.trailing_whitespace, "line")
+            ));
+        }
+        if self.trailing_blank_lines > 0 {
+            changes.push(format!(
+                "removed {}",
+                plural(self.trailing_blank_lines, "trailing blank line")
+            ));
+        }
+        match changes.is_empty() {
+            true => write!(f, "no changes"),
+            false => write!(f, "{}", changes.join(", ")),
+        }
+    }
+}
+
+/// Puzzle input in the form every parser expects, and what it took to get there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalized<'a> {
+    pub text: Cow<'a, str>,
+    pub changes: Changes,
+}
+
+/// Strip a byte order mark, turn every line ending into `\n`, and trim whitespace from the
+/// end of each line and blank lines from the end of the input
+///
+/// Line numbers are unchanged, so parse errors still point at the right line of the file.
+/// Input which is already normalized is borrowed rather than copied
+pub fn normalize(input: &str) -> Normalized<'_> {
+    let mut changes = Changes::default();
+    let text = match input.strip_prefix(BYTE_ORDER_MARK) {
+        Some(rest) => {
+            changes.byte_order_mark = true;
+            rest
+        }
+        None => input,
+    };
+
+    let mut lines = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let (line, ending) = match rest.find(['\r', '\n']) {
+            Some(i) if rest[i..].starts_with("\r\n") => (&rest[..i], i..i + 2),
+            Some(i) => (&rest[..i], i..i + 1),
+            None => (rest, rest.len()..rest.len()),
+        };
+        if ending.len() == 2 || rest[ending.clone()].starts_with('\r') {
+            changes.line_endings += 1;
+        }
+        rest = &rest[ending.end..];
+
+        let trimmed = line.trim_end();
+        if trimmed.len() != line.len() {
+            changes.trailing_whitespace += 1;
+        }
+        lines.push(trimmed);
+    }
+    while lines.last() == Some(&"") {
+        lines.pop();
+        changes.trailing_blank_lines += 1;
+    }
+
+    if changes.is_empty() {
+        return Normalized {
+            text: Cow::Borrowed(input),
+            changes,
+        };
+    }
+    let mut text = lines.join("\n");
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    Normalized {
+        text: Cow::Owned(text),
+        changes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_already_normalized() {
+        for input in ["", "1\n2\n", "1\n\n2", "  indented\n"] {
+            let normalized = normalize(input);
+            assert!(matches!(normalized.text, Cow::Borrowed(text) if text == input));
+            assert!(normalized.changes.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        let normalized = normalize("\u{feff}1,2 \r\n\r\n 3\t\r4\n\n \n");
+        assert_eq!(normalized.text, "1,2\n\n 3\n4\n");
+        assert_eq!(
+            normalized.changes,
+            Changes {
+                byte_order_mark: true,
+                line_endings: 3,
+                trailing_whitespace: 3,
+                trailing_blank_lines: 2,
+            }
+        );
+        assert_eq!(
+            normalized.changes.to_string(),
+            "removed a byte order mark, converted 3 line endings to \\n, trimmed trailing \
+             whitespace from 3 lines, removed 2 trailing blank lines"
+        );
+    }
+
+    #[test]
+    fn test_blank_input() {
+        let normalized = normalize("\r\n  \n");
+        assert_eq!(normalized.text, "");
+        assert_eq!(normalized.changes.trailing_blank_lines, 2);
+    }
+}
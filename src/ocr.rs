@@ -0,0 +1,176 @@
+use std::{error::Error, fmt};
+
+use crate::answer::Image;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// Columns from the start of one glyph to the start of the next
+const GLYPH_SPACING: usize = GLYPH_WIDTH + 1;
+
+/// The capital letters of the puzzles' 4x6 font, as `#`/`.` rows
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// The image isn't a whole number of glyphs
+    Size { width: usize, height: usize },
+    /// The glyphs at these positions (counting from 1) aren't letters we know
+    Unrecognized { positions: Vec<usize>, image: Image },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::Size { width, height } => write!(
+                f,
+                "a {}x{} image isn't a row of {}x{} letters",
+                width, height, GLYPH_WIDTH, GLYPH_HEIGHT
+            ),
+            OcrError::Unrecognized { positions, image } => {
+                let positions: Vec<String> = positions
+                    .iter()
+                    .map(|position| {
+                        let column = (position - 1) * GLYPH_SPACING;
+                        format!(
+                            "{} (columns {}-{})",
+                            position,
+                            column,
+                            column + GLYPH_WIDTH - 1
+                        )
+                    })
+                    .collect();
+                write!(
+                    f,
+                    "unrecognized letters at positions {} of:\n{}",
+                    positions.join(", "),
+                    image
+                )
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+/// Read the letters drawn in `image`, which must be exactly one row of glyphs, each followed
+/// by a blank column (except, optionally, the last)
+pub fn recognize(image: &Image) -> Result<String, OcrError> {
+    let (width, height) = (image.width(), image.height());
+    if height != GLYPH_HEIGHT || width == 0 || !matches!(width % GLYPH_SPACING, 0 | GLYPH_WIDTH) {
+        return Err(OcrError::Size { width, height });
+    }
+
+    let mut letters = String::new();
+    let mut unrecognized = Vec::new();
+    for (i, start) in (0..width).step_by(GLYPH_SPACING).enumerate() {
+        match letter_at(image, start) {
+            Some(letter) => letters.push(letter),
+            None => unrecognized.push(i + 1),
+        }
+    }
+
+    match unrecognized.is_empty() {
+        true => Ok(letters),
+        false => Err(OcrError::Unrecognized {
+            positions: unrecognized,
+            image: image.clone(),
+        }),
+    }
+}
+
+fn letter_at(image: &Image, start: usize) -> Option<char> {
+    let separated = (0..GLYPH_HEIGHT).all(|y| image.get(start + GLYPH_WIDTH, y) != Some(true));
+    if !separated {
+        return None;
+    }
+    FONT.iter()
+        .find(|(_, rows)| {
+            rows.iter().enumerate().all(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .all(|(x, pixel)| image.get(start + x, y) == Some(pixel == Image::LIT))
+            })
+        })
+        .map(|&(letter, _)| letter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EFLFJGRF: &str = "####.####.#....####...##..##..###..####.
+#....#....#....#.......#.#..#.#..#.#....
+###..###..#....###.....#.#....#..#.###..
+#....#....#....#.......#.#.##.###..#....
+#....#....#....#....#..#.#..#.#.#..#....
+####.#....####.#.....##...###.#..#.#....";
+
+    #[test]
+    fn test_recognize() {
+        let image: Image = EFLFJGRF.parse().unwrap();
+        assert_eq!(recognize(&image), Ok("EFLFJGRF".to_string()));
+
+        // Without the trailing blank column
+        let trimmed: String = EFLFJGRF
+            .lines()
+            .map(|line| &line[..39])
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(
+            recognize(&trimmed.parse().unwrap()),
+            Ok("EFLFJGRF".to_string())
+        );
+    }
+
+    #[test]
+    fn test_font() {
+        for (letter, rows) in FONT {
+            let image: Image = rows.join("\n").parse().unwrap();
+            assert_eq!(recognize(&image), Ok(letter.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_unrecognized() {
+        let image: Image = EFLFJGRF.replace("####.####", "####.#.##").parse().unwrap();
+        let error = recognize(&image).unwrap_err();
+        assert!(matches!(
+            &error,
+            OcrError::Unrecognized { positions, .. } if positions == &[2]
+        ));
+        assert!(error
+            .to_string()
+            .starts_with("unrecognized letters at positions 2 (columns 5-8) of:\n"));
+    }
+
+    #[test]
+    fn test_wrong_size() {
+        let image: Image = "#####\n#####".parse().unwrap();
+        assert_eq!(
+            recognize(&image),
+            Err(OcrError::Size {
+                width: 5,
+                height: 2
+            })
+        );
+    }
+}
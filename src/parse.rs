@@ -0,0 +1,302 @@
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+    str::FromStr,
+};
+
+/// A malformed line of puzzle input, located precisely enough to fix it by hand
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    /// 1-based line number within the input
+    pub line: usize,
+    /// 1-based character column within the line
+    pub column: usize,
+    /// The full text of the offending line
+    pub text: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "day {} input, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        writeln!(f, "  | {}", self.text)?;
+        write!(f, "  | {:>width$}", "^", width = self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+/// A single line of input, which remembers where it came from so it can build a `ParseError`
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u32,
+    /// 1-based line number within the input
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// An error pointing at the start of the line
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        self.error_at_column(1, message)
+    }
+
+    /// An error pointing at `token`, which must be a slice of this line's text
+    /// (otherwise the error points at the start of the line)
+    pub fn error_at(&self, token: &str, message: impl Into<String>) -> ParseError {
+        self.error_at_column(self.column_of(token), message)
+    }
+
+    pub fn error_at_column(&self, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column,
+            text: self.text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// The 1-based column at which `token` starts, where `token` is a slice of this line
+    pub fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    /// Parse `token` (a slice of this line) with `FromStr`, reporting its position on failure
+    pub fn parse<T>(&self, token: &'a str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        token
+            .parse()
+            .map_err(|e| self.error_at(token, format!("invalid value {:?}: {}", token, e)))
+    }
+
+    /// Split on `separator`, failing if it doesn't appear
+    pub fn split_once(&self, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(separator)
+            .ok_or_else(|| self.error(format!("expected {:?}", separator)))
+    }
+
+    /// Parse every character as a base 10 digit
+    pub fn digits(&self) -> Result<Vec<u32>, ParseError> {
+        self.text
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                c.to_digit(10).ok_or_else(|| {
+                    self.error_at_column(i + 1, format!("expected a digit, found {:?}", c))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Iterate over the lines of `input`, numbered from 1
+pub fn lines(day: u32, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+/// An error for input which ran out before we found what we needed
+pub fn unexpected_end(day: u32, input: &str, message: impl Into<String>) -> ParseError {
+    unexpected_end_after(day, input.lines().count(), message)
+}
+
+/// As `unexpected_end`, for input of `lines` lines
+pub fn unexpected_end_after(day: u32, lines: usize, message: impl Into<String>) -> ParseError {
+    ParseError {
+        day,
+        line: lines + 1,
+        column: 1,
+        text: String::new(),
+        message: message.into(),
+    }
+}
+
+/// Why input read from a stream couldn't be solved
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+    /// The line was read, but the solution couldn't go on from it
+    Solve {
+        day: u32,
+        line: usize,
+        error: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl StreamError {
+    /// `error` stopped the solution at `line`
+    pub fn solve(line: Line, error: impl Error + Send + Sync + 'static) -> Self {
+        StreamError::Solve {
+            day: line.day,
+            line: line.number,
+            error: Box::new(error),
+        }
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "could not read input: {}", e),
+            StreamError::Parse(e) => write!(f, "{}", e),
+            StreamError::Solve { day, line, error } => {
+                write!(f, "day {} input, line {}: {}", day, line, error)
+            }
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(e) => Some(e),
+            StreamError::Parse(e) => Some(e),
+            StreamError::Solve { error, .. } => Some(error.as_ref()),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        StreamError::Parse(e)
+    }
+}
+
+/// Call `f` with each line read from `reader`, numbered from 1, returning how many there were
+/// or the first error `f` returned
+///
+/// Lines are cleaned up as `normalize` would (other than lone `\r` line endings), so `f` sees
+/// the same lines as a parser given the whole input. Only one line is held at a time, plus a
+/// count of blank lines which may turn out to be trailing
+pub fn for_each_line(
+    day: u32,
+    mut reader: impl BufRead,
+    mut f: impl FnMut(Line<'_>) -> Result<(), StreamError>,
+) -> Result<usize, StreamError> {
+    let mut buffer = String::new();
+    let mut number = 0;
+    let mut blank_lines = 0;
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            // Blank lines at the end are dropped
+            return Ok(number - blank_lines);
+        }
+        number += 1;
+        let mut text = buffer.as_str();
+        if number == 1 {
+            text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        }
+        let text = text.trim_end();
+        if text.is_empty() {
+            blank_lines += 1;
+            continue;
+        }
+
+        for blank in number - blank_lines..number {
+            f(Line {
+                day,
+                number: blank,
+                text: "",
+            })?;
+        }
+        blank_lines = 0;
+        f(Line { day, number, text })?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_are_numbered_from_one() {
+        let numbers: Vec<usize> = lines(1, "a\nb\nc").map(|l| l.number).collect();
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let line = lines(5, "1,2 -> 3,x").next().unwrap();
+        let error = line.parse::<usize>(&line.text[9..]).unwrap_err();
+        assert_eq!(error.day, 5);
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 10);
+        assert_eq!(error.text, "1,2 -> 3,x");
+    }
+
+    #[test]
+    fn test_digits() {
+        let line = lines(9, "12a4").next().unwrap();
+        assert_eq!(line.digits().unwrap_err().column, 3);
+        let line = lines(9, "1234").next().unwrap();
+        assert_eq!(line.digits(), Ok(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_for_each_line() {
+        let input = "\u{feff}a \r\n\r\nb\n\n \n";
+        let mut streamed = Vec::new();
+        let count = for_each_line(1, input.as_bytes(), |line| {
+            streamed.push((line.number, line.text.to_string()));
+            Ok(())
+        });
+        assert_eq!(count.unwrap(), 3);
+
+        let normalized = crate::normalize::normalize(input);
+        let expected: Vec<(usize, String)> = lines(1, &normalized.text)
+            .map(|line| (line.number, line.text.to_string()))
+            .collect();
+        assert_eq!(streamed, expected);
+    }
+
+    #[test]
+    fn test_for_each_line_stops_at_errors() {
+        let mut seen = 0;
+        let result = for_each_line(1, "a\nb\nc\n".as_bytes(), |line| {
+            seen += 1;
+            match line.text {
+                "b" => Err(line.error("no b").into()),
+                _ => Ok(()),
+            }
+        });
+        assert!(matches!(result, Err(StreamError::Parse(e)) if e.line == 2));
+        assert_eq!(seen, 2);
+    }
+
+    #[test]
+    fn test_display() {
+        let line = lines(1, "12x").next().unwrap();
+        assert_eq!(
+            line.error_at(&line.text[2..], "bad").to_string(),
+            "day 1 input, line 1, column 3: bad\n  | 12x\n  |   ^"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+/// A graph to search, described by the edges leaving each node
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// Each node one step from `node`, with the cost of that step
+    fn neighbours(&self, node: &Self::Node) -> impl Iterator<Item = (Self::Node, u64)>;
+}
+
+/// A graph defined by a closure returning each node's neighbours and step costs
+pub struct Successors<N, F> {
+    successors: F,
+    node: PhantomData<fn(&N)>,
+}
+
+impl<N, F> Successors<N, F>
+where
+    F: Fn(&N) -> Vec<(N, u64)>,
+{
+    pub fn new(successors: F) -> Self {
+        Successors {
+            successors,
+            node: PhantomData,
+        }
+    }
+}
+
+impl<N, F> Graph for Successors<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: Fn(&N) -> Vec<(N, u64)>,
+{
+    type Node = N;
+
+    fn neighbours(&self, node: &N) -> impl Iterator<Item = (N, u64)> {
+        (self.successors)(node).into_iter()
+    }
+}
+
+/// A route through a graph, from the start node to the goal inclusive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: u64,
+}
+
+/// Every node reached by a search, and how
+struct Visited<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    /// The cheapest known cost of reaching each node, and the node it was reached from
+    best: Vec<(u64, Option<usize>)>,
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new(start: N) -> Self {
+        Visited {
+            nodes: vec![start.clone()],
+            indices: HashMap::from([(start, 0)]),
+            best: vec![(0, None)],
+        }
+    }
+
+    /// Record reaching `node` from `parent` at `cost`, returning its index if that's cheaper
+    /// than any way we've found before
+    fn relax(&mut self, node: N, cost: u64, parent: usize) -> Option<usize> {
+        match self.indices.entry(node) {
+            hash_map::Entry::Occupied(entry) => {
+                let i = *entry.get();
+                if cost >= self.best[i].0 {
+                    return None;
+                }
+                self.best[i] = (cost, Some(parent));
+                Some(i)
+            }
+            hash_map::Entry::Vacant(entry) => {
+                let i = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                entry.insert(i);
+                self.best.push((cost, Some(parent)));
+                Some(i)
+            }
+        }
+    }
+
+    /// Follow the parents back from `end` to the start
+    fn path_to(&self, end: usize) -> Path<N> {
+        let mut nodes = vec![self.nodes[end].clone()];
+        let mut current = end;
+        while let Some(parent) = self.best[current].1 {
+            nodes.push(self.nodes[parent].clone());
+            current = parent;
+        }
+        nodes.reverse();
+        Path {
+            nodes,
+            cost: self.best[end].0,
+        }
+    }
+}
+
+/// The cheapest path from `start` to any node satisfying `is_goal`
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// As `dijkstra`, but exploring nodes in order of their cost so far plus `heuristic`
+///
+/// The heuristic must never overestimate the remaining cost to a goal, or the path found may
+/// not be the cheapest
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> u64,
+) -> Option<Path<G::Node>> {
+    let mut to_visit = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut visited = Visited::new(start);
+
+    while let Some(Reverse((_, cost, i))) = to_visit.pop() {
+        if cost > visited.best[i].0 {
+            // We've since found a cheaper way here
+            continue;
+        }
+        if is_goal(&visited.nodes[i]) {
+            return Some(visited.path_to(i));
+        }
+
+        let node = visited.nodes[i].clone();
+        for (neighbour, step) in graph.neighbours(&node) {
+            let estimate = heuristic(&neighbour);
+            let new_cost = cost + step;
+            if let Some(j) = visited.relax(neighbour, new_cost, i) {
+                to_visit.push(Reverse((new_cost + estimate, new_cost, j)));
+            }
+        }
+    }
+    None
+}
+
+/// The cheapest cost of reaching every node reachable from `start`
+pub fn distances<G: Graph>(graph: &G, start: G::Node) -> HashMap<G::Node, u64> {
+    let mut to_visit = BinaryHeap::from([Reverse((0, 0))]);
+    let mut visited = Visited::new(start);
+
+    while let Some(Reverse((cost, i))) = to_visit.pop() {
+        if cost > visited.best[i].0 {
+            continue;
+        }
+        let node = visited.nodes[i].clone();
+        for (neighbour, step) in graph.neighbours(&node) {
+            if let Some(j) = visited.relax(neighbour, cost + step, i) {
+                to_visit.push(Reverse((cost + step, j)));
+            }
+        }
+    }
+
+    visited
+        .nodes
+        .into_iter()
+        .zip(visited.best)
+        .map(|(node, (cost, _))| (node, cost))
+        .collect()
+}
+
+/// The path from `start` to any node satisfying `is_goal` with the fewest steps, ignoring
+/// step costs; the path's cost is its number of steps
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    let mut to_visit = VecDeque::from([0]);
+    let mut visited = Visited::new(start);
+
+    while let Some(i) = to_visit.pop_front() {
+        if is_goal(&visited.nodes[i]) {
+            return Some(visited.path_to(i));
+        }
+        let steps = visited.best[i].0 + 1;
+        let node = visited.nodes[i].clone();
+        for (neighbour, _) in graph.neighbours(&node) {
+            // Breadth first, so the first route to any node is the shortest
+            if !visited.indices.contains_key(&neighbour) {
+                to_visit.extend(visited.relax(neighbour, steps, i));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -1-> b -1-> c -1-> d, plus a shortcut a -5-> d and a dead end c -> e
+    fn example() -> Successors<char, impl Fn(&char) -> Vec<(char, u64)>> {
+        Successors::new(|node: &char| match node {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1), ('e', 1)],
+            _ => vec![],
+        })
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(
+            dijkstra(&example(), 'a', |&n| n == 'd'),
+            Some(Path {
+                nodes: vec!['a', 'b', 'c', 'd'],
+                cost: 3
+            })
+        );
+        assert_eq!(dijkstra(&example(), 'b', |&n| n == 'a'), None);
+        assert_eq!(
+            dijkstra(&example(), 'a', |&n| n == 'a').map(|path| path.nodes),
+            Some(vec!['a'])
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        // Each step moves at most 3 for at least 1, so a third of the distance is a lower bound
+        let line = Successors::new(|&n: &i64| vec![(n - 1, 1), (n + 1, 1), (n + 3, 2)]);
+        let path = astar(&line, 0, |&n| n == 10, |&n| (10 - n).unsigned_abs() / 3).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.nodes.first(), Some(&0));
+        assert_eq!(path.nodes.last(), Some(&10));
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(&example(), 'a', |&n| n == 'd').unwrap();
+        assert_eq!(path.nodes, vec!['a', 'd']);
+        assert_eq!(path.cost, 1);
+    }
+
+    #[test]
+    fn test_distances() {
+        let distances = distances(&example(), 'a');
+        assert_eq!(distances.len(), 5);
+        assert_eq!(distances[&'d'], 3);
+        assert_eq!(distances[&'e'], 3);
+    }
+}
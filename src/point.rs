@@ -0,0 +1,341 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::grid::Position;
+
+/// A point (or vector) on a plane, where `y` grows downwards as it does in puzzle grids
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point (or vector) in space
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2::new(0, 0);
+    pub const UP: Point2 = Point2::new(0, -1);
+    pub const DOWN: Point2 = Point2::new(0, 1);
+    pub const LEFT: Point2 = Point2::new(-1, 0);
+    pub const RIGHT: Point2 = Point2::new(1, 0);
+
+    /// Unit steps up, right, down and left
+    pub const DIRECTIONS4: [Point2; 4] = [Point2::UP, Point2::RIGHT, Point2::DOWN, Point2::LEFT];
+
+    /// As `DIRECTIONS4`, plus the diagonals, in reading order
+    pub const DIRECTIONS8: [Point2; 8] = [
+        Point2::new(-1, -1),
+        Point2::UP,
+        Point2::new(1, -1),
+        Point2::LEFT,
+        Point2::RIGHT,
+        Point2::new(-1, 1),
+        Point2::DOWN,
+        Point2::new(1, 1),
+    ];
+
+    pub fn manhattan(self, other: Point2) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The unit step (including diagonals) pointing the same way as this vector
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// A quarter turn clockwise, as seen with `y` growing downwards
+    pub fn rotate_right(self) -> Point2 {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// A quarter turn anticlockwise, as seen with `y` growing downwards
+    pub fn rotate_left(self) -> Point2 {
+        Point2::new(self.y, -self.x)
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Point2::DIRECTIONS4.into_iter().map(move |d| self + d)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2> {
+        Point2::DIRECTIONS8.into_iter().map(move |d| self + d)
+    }
+
+    /// Every point from here to `end` inclusive, which must lie on a horizontal, vertical or
+    /// 45 degree line
+    ///
+    /// Panics for any other line, as it would pass between points
+    pub fn segment_to(self, end: Point2) -> impl Iterator<Item = Point2> {
+        let delta = end - self;
+        assert!(
+            delta.x == 0 || delta.y == 0 || delta.x.abs() == delta.y.abs(),
+            "{} -> {} isn't horizontal, vertical or diagonal",
+            self,
+            end
+        );
+        let step = delta.signum();
+        let steps = delta.x.abs().max(delta.y.abs());
+        (0..=steps).map(move |i| self + step * i)
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub fn manhattan(self, other: Point3) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn signum(self) -> Point3 {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// A quarter turn about the x axis (from y towards z)
+    pub fn rotate_x(self) -> Point3 {
+        Point3::new(self.x, -self.z, self.y)
+    }
+
+    /// A quarter turn about the y axis (from z towards x)
+    pub fn rotate_y(self) -> Point3 {
+        Point3::new(self.z, self.y, -self.x)
+    }
+
+    /// A quarter turn about the z axis (from x towards y)
+    pub fn rotate_z(self) -> Point3 {
+        Point3::new(-self.y, self.x, self.z)
+    }
+
+    /// This point under each of the 24 rotations which keep axes aligned (the first being
+    /// the identity), in a fixed order so the same index always means the same rotation
+    pub fn orientations(self) -> impl Iterator<Item = Point3> {
+        // Point the x axis each of 6 ways, then spin about it 4 times
+        let facings = [
+            self,
+            self.rotate_y(),
+            self.rotate_y().rotate_y(),
+            self.rotate_y().rotate_y().rotate_y(),
+            self.rotate_z(),
+            self.rotate_z().rotate_z().rotate_z(),
+        ];
+        facings
+            .into_iter()
+            .flat_map(|facing| std::iter::successors(Some(facing), |p| Some(p.rotate_x())).take(4))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, n: T) -> Self {
+        Point2::new(self.x * n, self.y * n)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, n: T) -> Self {
+        Point3::new(self.x * n, self.y * n, self.z * n)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point3<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl From<Position> for Point2 {
+    /// Panics if a coordinate doesn't fit in an `i64`
+    fn from((x, y): Position) -> Self {
+        Point2::new(
+            i64::try_from(x).expect("x fits in an i64"),
+            i64::try_from(y).expect("y fits in an i64"),
+        )
+    }
+}
+
+/// Grid positions can't be negative
+impl TryFrom<Point2> for Position {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(point: Point2) -> Result<Self, Self::Error> {
+        Ok((usize::try_from(point.x)?, usize::try_from(point.y)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 5);
+        assert_eq!(a + b, Point2::new(-2, 3));
+        assert_eq!(a - b, Point2::new(4, -7));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(-1, -1, -1),
+            Point3::new(0, 1, 2)
+        );
+        assert_eq!(Point3::new(1, 2, 3).manhattan(Point3::ORIGIN), 6);
+    }
+
+    #[test]
+    fn test_rotations() {
+        assert_eq!(Point2::UP.rotate_right(), Point2::RIGHT);
+        assert_eq!(Point2::UP.rotate_left(), Point2::LEFT);
+        let p = Point2::new(3, -7);
+        assert_eq!(p.rotate_right().rotate_left(), p);
+        assert_eq!(
+            p.rotate_right()
+                .rotate_right()
+                .rotate_right()
+                .rotate_right(),
+            p
+        );
+    }
+
+    #[test]
+    fn test_orientations() {
+        let orientations: Vec<Point3> = Point3::new(1, 2, 3).orientations().collect();
+        assert_eq!(orientations[0], Point3::new(1, 2, 3));
+        assert_eq!(orientations.iter().collect::<HashSet<_>>().len(), 24);
+        // Rotations preserve distance from the origin
+        assert!(orientations
+            .iter()
+            .all(|p| p.x.abs() + p.y.abs() + p.z.abs() == 6));
+    }
+
+    #[test]
+    fn test_segment_to() {
+        let segment: Vec<Point2> = Point2::new(9, 7).segment_to(Point2::new(7, 9)).collect();
+        assert_eq!(
+            segment,
+            vec![Point2::new(9, 7), Point2::new(8, 8), Point2::new(7, 9)]
+        );
+        assert_eq!(Point2::new(0, -1).segment_to(Point2::new(0, -3)).count(), 3);
+        assert_eq!(Point2::ORIGIN.segment_to(Point2::ORIGIN).count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "isn't horizontal, vertical or diagonal")]
+    fn test_segment_to_rejects_other_angles() {
+        Point2::ORIGIN.segment_to(Point2::new(1, 2)).for_each(drop);
+    }
+
+    #[test]
+    fn test_positions() {
+        assert_eq!(Position::try_from(Point2::new(2, 3)), Ok((2, 3)));
+        assert!(Position::try_from(Point2::new(-1, 3)).is_err());
+        assert_eq!(Point2::from((4, 5)), Point2::new(4, 5));
+    }
+}
@@ -0,0 +1,240 @@
+use std::any::Any;
+
+use crate::{
+    answer::Answer,
+    normalize::{self, Changes},
+    parse::ParseError,
+    solution::{Params, Part, Solution},
+    y2021,
+};
+
+type AnyInput = Box<dyn Any + Send + Sync>;
+
+/// A `Solution` with its input type erased, so every day can be driven the same way
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    /// The names of the `Params` this day accepts
+    pub params: &'static [&'static str],
+    parse: fn(&str) -> Result<AnyInput, ParseError>,
+    part1: fn(&AnyInput, &Params) -> Answer,
+    part2: fn(&AnyInput, &Params) -> Answer,
+}
+
+/// The parsed input for one day, which can only be solved by that day's `Entry`
+pub struct Parsed {
+    year: u32,
+    day: u32,
+    input: AnyInput,
+    changes: Changes,
+}
+
+impl Parsed {
+    /// What normalizing the input changed before it was parsed
+    pub fn changes(&self) -> Changes {
+        self.changes
+    }
+}
+
+impl Entry {
+    pub const fn of<S>() -> Self
+    where
+        S: Solution,
+        S::Input: Send + Sync + 'static,
+    {
+        Entry {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            params: S::PARAMS,
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+        }
+    }
+
+    /// Normalize `input` (so line endings, stray whitespace and the like don't matter) then
+    /// parse it
+    pub fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        let normalized = normalize::normalize(input);
+        Ok(Parsed {
+            year: self.year,
+            day: self.day,
+            input: (self.parse)(&normalized.text)?,
+            changes: normalized.changes,
+        })
+    }
+
+    /// Solve one part from input already parsed by this entry
+    ///
+    /// Panics if `parsed` came from a different day
+    pub fn run(&self, part: Part, parsed: &Parsed) -> Answer {
+        self.run_with(part, parsed, &Params::default())
+    }
+
+    /// As `run`, with some of the puzzle's constants overridden
+    ///
+    /// Panics if this day doesn't accept one of the `params`
+    pub fn run_with(&self, part: Part, parsed: &Parsed, params: &Params) -> Answer {
+        if let Some(name) = params.names().find(|name| !self.params.contains(name)) {
+            panic!("{} day {} has no parameter {:?}", self.year, self.day, name);
+        }
+        assert_eq!(
+            (parsed.year, parsed.day),
+            (self.year, self.day),
+            "input parsed for {} day {} can't be solved by {} day {}",
+            parsed.year,
+            parsed.day,
+            self.year,
+            self.day
+        );
+        match part {
+            Part::One => (self.part1)(&parsed.input, params),
+            Part::Two => (self.part2)(&parsed.input, params),
+        }
+    }
+
+    /// Parse `input` and solve one part
+    pub fn solve(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        Ok(self.run(part, &self.parse(input)?))
+    }
+}
+
+fn parse<S>(input: &str) -> Result<AnyInput, ParseError>
+where
+    S: Solution,
+    S::Input: Send + Sync + 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn downcast<S>(input: &AnyInput) -> &S::Input
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("input was parsed by the same solution")
+}
+
+fn part1<S>(input: &AnyInput, params: &Params) -> Answer
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    S::part1_with(downcast::<S>(input), params).into()
+}
+
+fn part2<S>(input: &AnyInput, params: &Params) -> Answer
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    S::part2_with(downcast::<S>(input), params).into()
+}
+
+/// One event's solved days, in order
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [Entry],
+}
+
+impl Year {
+    pub fn get(&self, day: u32) -> Option<&'static Entry> {
+        self.days.iter().find(|entry| entry.day == day)
+    }
+}
+
+static YEARS: [Year; 1] = [Year {
+    year: 2021,
+    days: &y2021::DAYS,
+}];
+
+/// The year used when none is given
+pub const LATEST_YEAR: u32 = 2021;
+
+/// Every year with solutions, in order
+pub fn years() -> &'static [Year] {
+    &YEARS
+}
+
+pub fn year(year: u32) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+pub fn get(year: u32, day: u32) -> Option<&'static Entry> {
+    self::year(year)?.get(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_in_order() {
+        for year in years() {
+            assert!(year.days.iter().all(|entry| entry.year == year.year));
+            assert!(year.days.windows(2).all(|pair| pair[0].day < pair[1].day));
+        }
+        let days: Vec<u32> = year(2021).unwrap().days.iter().map(|e| e.day).collect();
+        assert_eq!(days, (1..=15).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get(2021, 6).map(|entry| entry.title), Some("Lanternfish"));
+        assert!(get(2021, 25).is_none());
+        assert!(get(2015, 6).is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            get(2021, 6).unwrap().solve(Part::One, "3,4,3,1,2"),
+            Ok(Answer::from(5934))
+        );
+    }
+
+    #[test]
+    fn test_parse_normalizes_input() {
+        let entry = get(2021, 4).unwrap();
+        let input = "\u{feff}1,2,3,4,5\r\n\r\n 1 2 3 4 5\r\n 1 2 3 4 5\r\n 1 2 3 4 5 \r\n\
+                     7 2 3 4 5\r\n 4 2 3 4 9\r\n\r\n\r\n";
+        let parsed = entry.parse(input).unwrap();
+        assert_eq!(entry.run(Part::One, &parsed), Answer::from(16 * 5));
+        let changes = parsed.changes();
+        assert!(changes.byte_order_mark);
+        assert_eq!(changes.line_endings, 9);
+        assert_eq!(changes.trailing_whitespace, 1);
+        assert_eq!(changes.trailing_blank_lines, 2);
+    }
+
+    #[test]
+    fn test_run_with_params() {
+        let entry = get(2021, 6).unwrap();
+        let parsed = entry.parse("3,4,3,1,2").unwrap();
+        let params = Params::from([("days", 18)]);
+        assert_eq!(
+            entry.run_with(Part::One, &parsed, &params),
+            Answer::from(26)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "2021 day 7 has no parameter \"days\"")]
+    fn test_run_with_unknown_params() {
+        let entry = get(2021, 7).unwrap();
+        let parsed = entry.parse("1,2").unwrap();
+        entry.run_with(Part::One, &parsed, &Params::from([("days", 18)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be solved by 2021 day 7")]
+    fn test_run_with_another_days_input() {
+        let parsed = get(2021, 6).unwrap().parse("3,4,3,1,2").unwrap();
+        get(2021, 7).unwrap().run(Part::One, &parsed);
+    }
+}
@@ -0,0 +1,290 @@
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    normalize::Changes,
+    parse::ParseError,
+    registry::{Entry, Parsed},
+    solution::Part,
+};
+
+/// Why a day couldn't be solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Parse(ParseError),
+    /// The solver panicked, with this message
+    Panic(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Parse(e) => write!(f, "{}", e),
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+/// The outcome of solving one part
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Result<Answer, Failure>,
+    pub elapsed: Duration,
+}
+
+/// The outcome of parsing a day's input and then solving some of its parts
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub day: u32,
+    /// What normalizing the input changed, if it could be parsed
+    pub changes: Changes,
+    pub parse_time: Duration,
+    /// Each part which was solved, or why the input couldn't be parsed
+    pub parts: Result<Vec<Solved>, Failure>,
+}
+
+/// Run `f`, turning a panic into its message
+///
+/// Callers will usually want to install a silent panic hook first, so the panic isn't also
+/// printed by the default hook
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+/// Parse `input` once, then solve each of `parts`, timing each phase
+pub fn solve(entry: &Entry, input: &str, parts: &[Part]) -> DayResult {
+    let (parsed, parse_time) = parse_timed(entry, input);
+    let changes = changes(&parsed);
+    let parts = parsed.map(|parsed| {
+        parts
+            .iter()
+            .map(|&part| run_timed(entry, part, &parsed))
+            .collect()
+    });
+
+    DayResult {
+        day: entry.day,
+        changes,
+        parse_time,
+        parts,
+    }
+}
+
+/// As `solve` for every day in `days`, but on a pool of at most `threads` threads
+///
+/// Every input is parsed, then every part of every day is solved, each as its own job so one
+/// slow part doesn't hold up the rest of its day. The results are in the same order as
+/// `days`, whatever order they finish in, alongside the most threads that were ever busy at
+/// once: never more than `threads` or the jobs there were to share, and at least 1
+pub fn solve_parallel(
+    days: &[(&Entry, &str)],
+    parts: &[Part],
+    threads: usize,
+) -> (Vec<DayResult>, usize) {
+    let (parsed, parse_threads) =
+        parallel_map(days, threads, |&(entry, input)| parse_timed(entry, input));
+
+    let jobs: Vec<(usize, Part)> = parsed
+        .iter()
+        .enumerate()
+        .filter(|(_, (parsed, _))| parsed.is_ok())
+        .flat_map(|(i, _)| parts.iter().map(move |&part| (i, part)))
+        .collect();
+    let (solved, solve_threads) = parallel_map(&jobs, threads, |&(i, part)| match &parsed[i].0 {
+        Ok(input) => run_timed(days[i].0, part, input),
+        Err(_) => unreachable!("only parsed inputs are solved"),
+    });
+    let mut solved = solved.into_iter();
+
+    let results = parsed
+        .into_iter()
+        .zip(days)
+        .map(|((parsed, parse_time), (entry, _))| DayResult {
+            day: entry.day,
+            changes: changes(&parsed),
+            parse_time,
+            // Jobs were queued day by day, so each day's parts are next in line
+            parts: parsed.map(|_| solved.by_ref().take(parts.len()).collect()),
+        })
+        .collect();
+    (results, parse_threads.max(solve_threads))
+}
+
+fn parse_timed(entry: &Entry, input: &str) -> (Result<Parsed, Failure>, Duration) {
+    let start = Instant::now();
+    let parsed = catch_panic(|| entry.parse(input));
+    let parse_time = start.elapsed();
+    let parsed = match parsed {
+        Ok(Ok(parsed)) => Ok(parsed),
+        Ok(Err(e)) => Err(Failure::Parse(e)),
+        Err(message) => Err(Failure::Panic(message)),
+    };
+    (parsed, parse_time)
+}
+
+fn changes(parsed: &Result<Parsed, Failure>) -> Changes {
+    parsed.as_ref().map(Parsed::changes).unwrap_or_default()
+}
+
+fn run_timed(entry: &Entry, part: Part, parsed: &Parsed) -> Solved {
+    let start = Instant::now();
+    let answer = catch_panic(|| entry.run(part, parsed)).map_err(Failure::Panic);
+    Solved {
+        part,
+        answer,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Call `f` with each item on a pool of at most `threads` threads, returning the results in
+/// the same order as the items
+///
+/// `f` shouldn't panic, as a panic is passed on once every thread has finished
+fn parallel_map<T: Sync, R: Send>(
+    items: &[T],
+    threads: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> (Vec<R>, usize) {
+    let next = AtomicUsize::new(0);
+    let threads = threads.clamp(1, items.len().max(1));
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                let result = f(item);
+                results.lock().expect("no thread panics holding the lock")[i] = Some(result);
+            });
+        }
+    });
+
+    let results = results
+        .into_inner()
+        .expect("no thread panics holding the lock")
+        .into_iter()
+        .map(|result| result.expect("every item was processed"))
+        .collect();
+    (results, threads)
+}
+
+impl DayResult {
+    /// The time spent parsing and solving every part
+    pub fn total_time(&self) -> Duration {
+        let solving: Duration = match &self.parts {
+            Ok(parts) => parts.iter().map(|solved| solved.elapsed).sum(),
+            Err(_) => Duration::ZERO,
+        };
+        self.parse_time + solving
+    }
+
+    /// The answer to `part` or why there isn't one, if we tried to solve it
+    pub fn answer(&self, part: Part) -> Option<Result<&Answer, &Failure>> {
+        match &self.parts {
+            Ok(parts) => parts
+                .iter()
+                .find(|solved| solved.part == part)
+                .map(|solved| solved.answer.as_ref()),
+            Err(failure) => Some(Err(failure)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_solve() {
+        let result = solve(registry::get(2021, 6).unwrap(), "3,4,3,1,2", &Part::BOTH);
+        assert_eq!(result.answer(Part::One), Some(Ok(&Answer::from(5934))));
+        assert_eq!(
+            result.answer(Part::Two),
+            Some(Ok(&Answer::from(26984457539u64)))
+        );
+    }
+
+    #[test]
+    fn test_solve_parse_failure() {
+        let result = solve(registry::get(2021, 6).unwrap(), "3,x", &Part::BOTH);
+        assert!(matches!(result.parts, Err(Failure::Parse(_))));
+        assert!(matches!(
+            result.answer(Part::One),
+            Some(Err(Failure::Parse(_)))
+        ));
+    }
+
+    #[test]
+    fn test_solve_catches_panics() {
+        // Not enough numbers drawn for any board to win
+        let input = "1,2\n\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5";
+        let result = solve(registry::get(2021, 4).unwrap(), input, &[Part::One]);
+        assert_eq!(
+            result.answer(Part::One),
+            Some(Err(&Failure::Panic("No winning board".to_string())))
+        );
+        assert_eq!(result.answer(Part::Two), None);
+    }
+
+    #[test]
+    fn test_solve_parallel() {
+        let day4 = registry::get(2021, 4).unwrap();
+        let day6 = registry::get(2021, 6).unwrap();
+        let days = [
+            (day6, "3,4,3,1,2"),
+            (day4, "not a bingo game"),
+            (day6, "3,x"),
+            (day6, "1"),
+        ];
+        let (results, threads) = solve_parallel(&days, &Part::BOTH, 3);
+        assert_eq!(threads, 3);
+        // Only 3 parsed inputs with 1 part each to share out
+        assert_eq!(solve_parallel(&days, &[Part::One], 64).1, 4);
+
+        // Matches solving each day in turn
+        assert_eq!(results.len(), days.len());
+        for (result, (entry, input)) in results.iter().zip(days) {
+            let expected = solve(entry, input, &Part::BOTH);
+            assert_eq!(result.day, expected.day);
+            for part in Part::BOTH {
+                assert_eq!(result.answer(part), expected.answer(part));
+            }
+        }
+        assert!(results[1].parts.is_err());
+    }
+
+    #[test]
+    fn test_parallel_map() {
+        let items: Vec<u64> = (0..100).collect();
+        for (threads, used) in [(0, 1), (1, 1), (4, 4), (200, 100)] {
+            assert_eq!(
+                parallel_map(&items, threads, |&n| n * n),
+                (items.iter().map(|&n| n * n).collect::<Vec<_>>(), used)
+            );
+        }
+        assert_eq!(
+            parallel_map(&[] as &[u64], 4, |&n| n),
+            (Vec::<u64>::new(), 1)
+        );
+    }
+}
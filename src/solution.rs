@@ -0,0 +1,127 @@
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{answer::Answer, parse::ParseError};
+
+/// A day's puzzle: how to parse its input, and how to solve both parts from the parsed input
+pub trait Solution {
+    type Input;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
+
+    const YEAR: u32;
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    /// The names of any `Params` the parts accept
+    const PARAMS: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Output1;
+
+    fn part2(input: &Self::Input) -> Self::Output2;
+
+    /// As `part1`, with constants from the puzzle text overridden by `params`
+    fn part1_with(input: &Self::Input, _params: &Params) -> Self::Output1 {
+        Self::part1(input)
+    }
+
+    /// As `part2`, with constants from the puzzle text overridden by `params`
+    fn part2_with(input: &Self::Input, _params: &Params) -> Self::Output2 {
+        Self::part2(input)
+    }
+}
+
+/// Named numbers overriding constants the puzzle text fixes, such as how many steps to
+/// simulate, since examples often use smaller values than the real puzzle
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Params {
+    values: BTreeMap<String, u64>,
+}
+
+impl Params {
+    pub fn get(&self, name: &str) -> Option<u64> {
+        self.values.get(name).copied()
+    }
+
+    /// The value of `name` as a count, or `default` if it isn't set
+    ///
+    /// Panics if the value doesn't fit in a `usize`
+    pub fn count_or(&self, name: &str, default: usize) -> usize {
+        self.get(name).map_or(default, |value| {
+            usize::try_from(value).unwrap_or_else(|_| panic!("{} = {} is too big", name, value))
+        })
+    }
+
+    pub fn insert(&mut self, name: &str, value: u64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<const N: usize> From<[(&str, u64); N]> for Params {
+    fn from(values: [(&str, u64); N]) -> Self {
+        Params {
+            values: values
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let values: Vec<String> = self
+            .values
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        write!(f, "{}", values.join(", "))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("expected part 1 or 2, found {:?}", s)),
+        }
+    }
+}
@@ -0,0 +1,379 @@
+use std::{
+    error::Error,
+    fmt,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    registry::Entry,
+    runner::{self, Failure},
+    solution::Part,
+};
+
+/// The stages of solving a day, each of which is timed separately
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+}
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Phase::Part1,
+            Part::Two => Phase::Part2,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Phase::ALL
+            .into_iter()
+            .find(|phase| phase.to_string() == s)
+            .ok_or_else(|| format!("unknown phase {:?}", s))
+    }
+}
+
+/// Summary statistics over repeated runs, in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub mean_ns: u64,
+    /// The sample standard deviation, which is 0 for a single run
+    pub stddev_ns: u64,
+}
+
+impl Stats {
+    /// Panics if there are no samples
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Need at least one sample");
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+
+        let runs = nanos.len() as f64;
+        let mean = nanos.iter().map(|&n| n as f64).sum::<f64>() / runs;
+        let variance = match nanos.len() {
+            1 => 0.0,
+            _ => {
+                nanos
+                    .iter()
+                    .map(|&n| (n as f64 - mean).powi(2))
+                    .sum::<f64>()
+                    / (runs - 1.0)
+            }
+        };
+
+        Stats {
+            runs: nanos.len(),
+            min_ns: nanos[0],
+            median_ns: percentile(&nanos, 50),
+            p95_ns: percentile(&nanos, 95),
+            mean_ns: mean.round() as u64,
+            stddev_ns: variance.sqrt().round() as u64,
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted samples
+fn percentile(sorted: &[u64], percent: usize) -> u64 {
+    let rank = (percent * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u32,
+    pub phase: Phase,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+/// Timings for every phase of every day measured
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub measurements: Vec<Measurement>,
+}
+
+const CSV_HEADER: &str = "day,phase,runs,min_ns,median_ns,p95_ns,mean_ns,stddev_ns";
+const CSV_FIELDS: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportError(String);
+
+impl fmt::Display for ReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid timing report: {}", self.0)
+    }
+}
+
+impl Error for ReportError {}
+
+impl Report {
+    pub fn get(&self, day: u32, phase: Phase) -> Option<&Stats> {
+        self.measurements
+            .iter()
+            .find(|m| m.day == day && m.phase == phase)
+            .map(|m| &m.stats)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("reports are always serializable")
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, ReportError> {
+        serde_json::from_str(text).map_err(|e| ReportError(e.to_string()))
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{}\n", CSV_HEADER);
+        for Measurement { day, phase, stats } in &self.measurements {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                day,
+                phase,
+                stats.runs,
+                stats.min_ns,
+                stats.median_ns,
+                stats.p95_ns,
+                stats.mean_ns,
+                stats.stddev_ns
+            ));
+        }
+        csv
+    }
+
+    pub fn from_csv(text: &str) -> Result<Self, ReportError> {
+        let mut lines = text.lines().enumerate();
+        if lines.next().map(|(_, header)| header.trim()) != Some(CSV_HEADER) {
+            return Err(ReportError(format!("expected a `{}` header", CSV_HEADER)));
+        }
+
+        let measurements = lines
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let error = |message: String| ReportError(format!("line {}: {}", i + 1, message));
+                let fields: Vec<&str> = line.trim().split(',').collect();
+                if fields.len() != CSV_FIELDS {
+                    return Err(error(format!(
+                        "expected {} fields, found {}",
+                        CSV_FIELDS,
+                        fields.len()
+                    )));
+                }
+                let number = |field: &str| -> Result<u64, ReportError> {
+                    field
+                        .parse()
+                        .map_err(|e| error(format!("invalid number {:?}: {}", field, e)))
+                };
+                Ok(Measurement {
+                    day: number(fields[0])? as u32,
+                    phase: fields[1].parse().map_err(error)?,
+                    stats: Stats {
+                        runs: number(fields[2])? as usize,
+                        min_ns: number(fields[3])?,
+                        median_ns: number(fields[4])?,
+                        p95_ns: number(fields[5])?,
+                        mean_ns: number(fields[6])?,
+                        stddev_ns: number(fields[7])?,
+                    },
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Report { measurements })
+    }
+}
+
+/// Time parsing and both parts of `entry` over `runs` repetitions, after `warmup` untimed
+/// ones to fill caches and settle the CPU's clock speed
+pub fn measure(
+    entry: &Entry,
+    input: &str,
+    warmup: usize,
+    runs: usize,
+) -> Result<Vec<Measurement>, Failure> {
+    let mut samples: Vec<(Phase, Vec<Duration>)> = Phase::ALL
+        .iter()
+        .map(|&phase| (phase, Vec::new()))
+        .collect();
+
+    for _ in 0..warmup {
+        let parsed = runner::catch_panic(|| entry.parse(input))
+            .map_err(Failure::Panic)?
+            .map_err(Failure::Parse)?;
+        for part in Part::BOTH {
+            runner::catch_panic(|| entry.run(part, &parsed)).map_err(Failure::Panic)?;
+        }
+    }
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = runner::catch_panic(|| entry.parse(input))
+            .map_err(Failure::Panic)?
+            .map_err(Failure::Parse)?;
+        samples[0].1.push(start.elapsed());
+
+        for (i, part) in Part::BOTH.into_iter().enumerate() {
+            let start = Instant::now();
+            runner::catch_panic(|| entry.run(part, &parsed)).map_err(Failure::Panic)?;
+            samples[i + 1].1.push(start.elapsed());
+        }
+    }
+
+    Ok(samples
+        .into_iter()
+        .map(|(phase, samples)| Measurement {
+            day: entry.day,
+            phase,
+            stats: Stats::from_samples(&samples),
+        })
+        .collect())
+}
+
+/// How one phase's median time changed between two reports
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u32,
+    pub phase: Phase,
+    pub before_ns: u64,
+    pub after_ns: u64,
+    pub change_percent: f64,
+    pub regressed: bool,
+}
+
+/// Compare the medians of every phase in both reports, flagging any which slowed down by more
+/// than `threshold_percent`
+pub fn compare(baseline: &Report, current: &Report, threshold_percent: f64) -> Vec<Comparison> {
+    current
+        .measurements
+        .iter()
+        .filter_map(|m| {
+            let before = baseline.get(m.day, m.phase)?;
+            let change_percent = if before.median_ns == 0 {
+                0.0
+            } else {
+                (m.stats.median_ns as f64 - before.median_ns as f64) / before.median_ns as f64
+                    * 100.0
+            };
+            Some(Comparison {
+                day: m.day,
+                phase: m.phase,
+                before_ns: before.median_ns,
+                after_ns: m.stats.median_ns,
+                change_percent,
+                regressed: change_percent > threshold_percent,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    fn nanos(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&n| Duration::from_nanos(n)).collect()
+    }
+
+    fn report(medians: &[(u32, Phase, u64)]) -> Report {
+        Report {
+            measurements: medians
+                .iter()
+                .map(|&(day, phase, median_ns)| Measurement {
+                    day,
+                    phase,
+                    stats: Stats {
+                        runs: 1,
+                        min_ns: median_ns,
+                        median_ns,
+                        p95_ns: median_ns,
+                        mean_ns: median_ns,
+                        stddev_ns: 0,
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&nanos(&(1..=20).rev().collect::<Vec<_>>()));
+        assert_eq!(
+            stats,
+            Stats {
+                runs: 20,
+                min_ns: 1,
+                median_ns: 10,
+                p95_ns: 19,
+                mean_ns: 11,
+                // The square root of 35
+                stddev_ns: 6,
+            }
+        );
+        let single = Stats::from_samples(&nanos(&[7]));
+        assert_eq!((single.p95_ns, single.mean_ns, single.stddev_ns), (7, 7, 0));
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let report = report(&[(1, Phase::Parse, 100), (1, Phase::Part2, 2500)]);
+        assert_eq!(Report::from_json(&report.to_json()), Ok(report.clone()));
+        assert_eq!(Report::from_csv(&report.to_csv()), Ok(report));
+    }
+
+    #[test]
+    fn test_from_csv_errors() {
+        assert!(Report::from_csv("day,phase\n").is_err());
+        assert!(Report::from_csv(&format!("{}\n1,part3,1,1,1,1,1,0", CSV_HEADER)).is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let before = report(&[(1, Phase::Parse, 100), (1, Phase::Part1, 100)]);
+        let after = report(&[
+            (1, Phase::Parse, 105),
+            (1, Phase::Part1, 150),
+            (2, Phase::Parse, 1),
+        ]);
+        let comparisons = compare(&before, &after, 10.0);
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert_eq!(comparisons[1].change_percent, 50.0);
+    }
+
+    #[test]
+    fn test_measure() {
+        let measurements = measure(registry::get(2021, 6).unwrap(), "3,4,3,1,2", 2, 3).unwrap();
+        let phases: Vec<Phase> = measurements.iter().map(|m| m.phase).collect();
+        assert_eq!(phases, Phase::ALL);
+        assert!(measurements.iter().all(|m| m.stats.runs == 3));
+    }
+}
@@ -0,0 +1,326 @@
+use std::{
+    collections::{hash_map::RandomState, BTreeMap},
+    error::Error,
+    fmt,
+    hash::BuildHasher,
+    str::FromStr,
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::solution::Part;
+
+const HASH_PREFIX: &str = "sha256:";
+
+/// An answer we've previously submitted, either in plain text or as a salted hash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Plain(String),
+    Hashed { salt: String, digest: String },
+}
+
+impl Expected {
+    /// Hash `answer` with a freshly generated salt
+    pub fn hashed(answer: &str) -> Self {
+        let salt = format!("{:016x}", RandomState::new().hash_one(answer));
+        let digest = digest(&salt, answer);
+        Expected::Hashed { salt, digest }
+    }
+
+    pub fn matches(&self, answer: &str) -> bool {
+        match self {
+            Expected::Plain(expected) => expected == answer,
+            Expected::Hashed {
+                salt,
+                digest: expected,
+            } => &digest(salt, answer) == expected,
+        }
+    }
+}
+
+fn digest(salt: &str, answer: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(salt.as_bytes());
+    hasher.update(answer.as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Plain(answer) => write!(f, "{}", escape(answer)),
+            Expected::Hashed { salt, digest } => write!(f, "{}{}:{}", HASH_PREFIX, salt, digest),
+        }
+    }
+}
+
+/// Answers are stored one per line, so newlines (and the backslashes used to escape them)
+/// need escaping
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(text: &str) -> Result<String, String> {
+    let mut answer = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => answer.push('\n'),
+                Some('\\') => answer.push('\\'),
+                other => {
+                    return Err(format!(
+                        "invalid escape sequence \\{}",
+                        other.unwrap_or(' ')
+                    ))
+                }
+            },
+            c => answer.push(c),
+        }
+    }
+    Ok(answer)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnswersError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "answers file, line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for AnswersError {}
+
+/// The contents of an answers file: one `<day> <part> <answer>` line per solved part,
+/// ignoring blank lines and `#` comments
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u32, Part), Expected>,
+}
+
+impl Answers {
+    pub fn get(&self, day: u32, part: Part) -> Option<&Expected> {
+        self.entries.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u32, part: Part, expected: Expected) {
+        self.entries.insert((day, part), expected);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u32, Part, &Expected)> {
+        self.entries
+            .iter()
+            .map(|(&(day, part), expected)| (day, part, expected))
+    }
+
+    /// Compare a freshly computed `answer` (or the reason there isn't one) with what we expect
+    pub fn check(&self, day: u32, part: Part, answer: Result<&str, &str>) -> Outcome {
+        match (self.get(day, part), answer) {
+            (Some(expected), Ok(answer)) if expected.matches(answer) => Outcome::Correct,
+            (Some(expected), Ok(answer)) => Outcome::Incorrect {
+                expected: match expected {
+                    Expected::Plain(expected) => Some(expected.clone()),
+                    Expected::Hashed { .. } => None,
+                },
+                actual: answer.to_string(),
+            },
+            (None, Ok(answer)) => Outcome::New {
+                actual: answer.to_string(),
+            },
+            (_, Err(reason)) => Outcome::Missing {
+                reason: reason.to_string(),
+            },
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = AnswersError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        for (i, line) in text.lines().enumerate() {
+            let error = |message: String| AnswersError {
+                line: i + 1,
+                message,
+            };
+
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, ' ');
+            let (day, part, answer) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(day), Some(part), Some(answer)) => (day, part, answer),
+                _ => return Err(error("expected `<day> <part> <answer>`".to_string())),
+            };
+            let day: u32 = day
+                .parse()
+                .map_err(|e| error(format!("invalid day {:?}: {}", day, e)))?;
+            let part: Part = part.parse().map_err(error)?;
+
+            let expected = match answer.strip_prefix(HASH_PREFIX) {
+                Some(hash) => {
+                    let (salt, digest) = hash
+                        .split_once(':')
+                        .ok_or_else(|| error("expected `sha256:<salt>:<digest>`".to_string()))?;
+                    Expected::Hashed {
+                        salt: salt.to_string(),
+                        digest: digest.to_string(),
+                    }
+                }
+                None => Expected::Plain(unescape(answer).map_err(error)?),
+            };
+
+            if answers.entries.insert((day, part), expected).is_some() {
+                return Err(error(format!(
+                    "duplicate answer for day {} part {}",
+                    day, part
+                )));
+            }
+        }
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# <day> <part> <answer>, where the answer is plain text (escaping"
+        )?;
+        writeln!(f, "# newlines as \\n) or a salted `sha256:<salt>:<digest>`")?;
+        for (day, part, expected) in self.iter() {
+            writeln!(f, "{} {} {}", day, part, expected)?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of checking one day and part against the answers file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// `expected` is only known if the answer wasn't stored as a hash
+    Incorrect {
+        expected: Option<String>,
+        actual: String,
+    },
+    /// We have an answer, but nothing to check it against
+    New {
+        actual: String,
+    },
+    /// We couldn't produce an answer (no input, or the solver failed)
+    Missing {
+        reason: String,
+    },
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Incorrect { .. } | Outcome::Missing { .. })
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "ok"),
+            Outcome::Incorrect {
+                expected: Some(expected),
+                actual,
+            } => write!(f, "INCORRECT: expected {}, got {}", expected, actual),
+            Outcome::Incorrect {
+                expected: None,
+                actual,
+            } => write!(
+                f,
+                "INCORRECT: got {}, which doesn't match the stored hash",
+                actual
+            ),
+            Outcome::New { actual } => write!(f, "new answer {}", actual),
+            Outcome::Missing { reason } => write!(f, "MISSING: {}", reason),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "# comment
+1 1 1502
+1 2 sha256:abc:4f1dc5dd0f5d5d69e5fd47f3d2a8d36d1d2bb7b1c8d24f6e7d1a2b3c4d5e6f70
+
+13 2 \\n#.#\\\\n";
+
+    #[test]
+    fn test_parse() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+        assert_eq!(
+            answers.get(1, Part::One),
+            Some(&Expected::Plain("1502".to_string()))
+        );
+        assert!(matches!(
+            answers.get(1, Part::Two),
+            Some(Expected::Hashed { salt, .. }) if salt == "abc"
+        ));
+        assert_eq!(
+            answers.get(13, Part::Two),
+            Some(&Expected::Plain("\n#.#\\n".to_string()))
+        );
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("1 1 1\n1 3 2".parse::<Answers>().unwrap_err().line, 2);
+        assert_eq!("1 1 1\n1 1 2".parse::<Answers>().unwrap_err().line, 2);
+        assert_eq!("1 1".parse::<Answers>().unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(13, Part::Two, Expected::Plain("\n#.\\#".to_string()));
+        answers.insert(2, Part::One, Expected::hashed("150"));
+        assert_eq!(answers.to_string().parse::<Answers>(), Ok(answers));
+    }
+
+    #[test]
+    fn test_hashed() {
+        let expected = Expected::hashed("1502");
+        assert!(expected.matches("1502"));
+        assert!(!expected.matches("1503"));
+        assert!(!expected.to_string().contains("1502"));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers: Answers = "6 1 5934".parse().unwrap();
+        assert_eq!(answers.check(6, Part::One, Ok("5934")), Outcome::Correct);
+        assert_eq!(
+            answers.check(6, Part::One, Ok("26")),
+            Outcome::Incorrect {
+                expected: Some("5934".to_string()),
+                actual: "26".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(6, Part::Two, Ok("26")),
+            Outcome::New {
+                actual: "26".to_string()
+            }
+        );
+        assert!(answers.check(6, Part::One, Err("no input")).is_failure());
+    }
+}
@@ -0,0 +1,164 @@
+use std::{collections::VecDeque, io::BufRead};
+
+use crate::{
+    parse::{self, Line, ParseError, StreamError},
+    solution::Solution,
+};
+
+fn parse_depth(line: Line) -> Result<usize, ParseError> {
+    line.parse(line.text)
+}
+
+pub fn read_depths(input: &str) -> Result<Vec<usize>, ParseError> {
+    parse::lines(1, input).map(parse_depth).collect()
+}
+
+/// Both answers from one pass over `reader`, holding only the last three depths
+pub fn stream(reader: impl BufRead) -> Result<(usize, usize), StreamError> {
+    let mut recent: VecDeque<usize> = VecDeque::with_capacity(3);
+    let mut increases = 0;
+    let mut window_increases = 0;
+    parse::for_each_line(1, reader, |line| {
+        let depth = parse_depth(line)?;
+        if recent.back().is_some_and(|&previous| depth > previous) {
+            increases += 1;
+        }
+        if recent.len() == 3 {
+            // Neighbouring windows share two depths, so only the others need comparing
+            if depth > recent[0] {
+                window_increases += 1;
+            }
+            recent.pop_front();
+        }
+        recent.push_back(depth);
+        Ok(())
+    })?;
+    Ok((increases, window_increases))
+}
+
+/// Where the sum of each `window` consecutive depths grew
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Increases {
+    pub count: usize,
+    /// The index of the first depth in each window whose sum was more than the window before
+    pub positions: Vec<usize>,
+    /// The most increases in a row
+    pub longest_streak: usize,
+}
+
+/// Compare the sums of each pair of neighbouring windows of `window` depths
+///
+/// Neighbouring windows share all but their first and last depths, so rather than summing
+/// them we only compare `depths[i]` with `depths[i + window]`. Panics if `window` is 0
+pub fn count_increases(depths: &[usize], window: usize) -> Increases {
+    assert!(window > 0, "windows need at least one depth");
+    let mut increases = Increases::default();
+    let mut streak = 0;
+    for (i, (first, last)) in depths.iter().zip(depths.iter().skip(window)).enumerate() {
+        if last > first {
+            increases.count += 1;
+            increases.positions.push(i + 1);
+            streak += 1;
+            increases.longest_streak = increases.longest_streak.max(streak);
+        } else {
+            streak = 0;
+        }
+    }
+    increases
+}
+
+pub fn a(depths: &[usize]) -> usize {
+    count_increases(depths, 1).count
+}
+
+pub fn part2(depths: &[usize]) -> usize {
+    count_increases(depths, 3).count
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_depths(input)
+    }
+
+    fn part1(depths: &Self::Input) -> usize {
+        a(depths)
+    }
+
+    fn part2(depths: &Self::Input) -> usize {
+        part2(depths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream() {
+        let input = include_str!("../../fixtures/2021/day1.txt");
+        let depths = read_depths(input).unwrap();
+        assert_eq!(
+            stream(input.as_bytes()).unwrap(),
+            (a(&depths), part2(&depths))
+        );
+        assert_eq!(stream(&b""[..]).unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn test_count_increases() {
+        let depths = read_depths(include_str!("../../fixtures/2021/day1.txt")).unwrap();
+        assert_eq!(
+            count_increases(&depths, 1),
+            Increases {
+                count: 7,
+                positions: vec![1, 2, 3, 5, 6, 7, 9],
+                longest_streak: 3,
+            }
+        );
+        assert_eq!(
+            count_increases(&depths, 3),
+            Increases {
+                count: 5,
+                positions: vec![1, 4, 5, 6, 7],
+                longest_streak: 4,
+            }
+        );
+        assert_eq!(count_increases(&depths, 10), Increases::default());
+        assert_eq!(count_increases(&[], 2), Increases::default());
+    }
+
+    #[test]
+    fn test_count_increases_matches_window_sums() {
+        let depths = read_depths(include_str!("../../fixtures/2021/day1.txt")).unwrap();
+        for window in 1..=6 {
+            let sums: Vec<usize> = depths.windows(window).map(|w| w.iter().sum()).collect();
+            let expected = sums.windows(2).filter(|pair| pair[1] > pair[0]).count();
+            assert_eq!(count_increases(&depths, window).count, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "windows need at least one depth")]
+    fn test_count_increases_rejects_empty_windows() {
+        count_increases(&[1, 2], 0);
+    }
+
+    #[test]
+    fn test_stream_reports_bad_lines() {
+        let error = match stream("1\n2\nx\n".as_bytes()) {
+            Err(StreamError::Parse(error)) => error,
+            other => panic!("expected a parse error, got {:?}", other),
+        };
+        assert_eq!(error, read_depths("1\n2\nx\n").unwrap_err());
+    }
+}
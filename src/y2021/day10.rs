@@ -0,0 +1,246 @@
+use std::{collections::HashMap, io::BufRead};
+
+use crate::{
+    counter::Counter,
+    parse::{self, Line, ParseError, StreamError},
+    solution::Solution,
+};
+
+/// Check `line` contains only brackets
+fn check_brackets(line: Line<'_>) -> Result<&str, ParseError> {
+    match line
+        .text
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !matches!(c, '{' | '(' | '[' | '<' | '}' | ')' | ']' | '>'))
+    {
+        Some((i, c)) => {
+            Err(line.error_at_column(i + 1, format!("expected a bracket, found {:?}", c)))
+        }
+        None => Ok(line.text),
+    }
+}
+
+/// Check every line contains only brackets
+pub fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    parse::lines(10, input)
+        .map(|line| check_brackets(line).map(str::to_string))
+        .collect()
+}
+
+pub fn part_1(lines: &[String]) -> usize {
+    let count_of_illegal_chars = lines
+        .iter()
+        .filter_map(|line| find_first_illegal_character(line))
+        .collect();
+    score_illegal_characters(count_of_illegal_chars)
+}
+
+fn build_character_pair_map() -> HashMap<char, char> {
+    HashMap::from_iter([('}', '{'), (')', '('), ('>', '<'), (']', '[')])
+}
+
+fn find_first_illegal_character(line: &str) -> Option<char> {
+    let pairs = build_character_pair_map();
+    let mut stack = Vec::new();
+
+    for c in line.chars() {
+        match c {
+            '{' | '(' | '[' | '<' => stack.push(c),
+            '}' | ')' | ']' | '>' => {
+                let opening_symbol = *pairs.get(&c).unwrap();
+                let paired_symbol = stack.pop();
+                if paired_symbol != Some(opening_symbol) {
+                    return Some(c);
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+    None
+}
+
+fn score_illegal_characters(character_counts: Counter<char>) -> usize {
+    let scores: HashMap<char, usize> =
+        HashMap::from_iter([(')', 3), (']', 57), ('}', 1197), ('>', 25137)]);
+    character_counts
+        .into_iter()
+        .map(|(ch, count)| scores.get(&ch).unwrap() * count as usize)
+        .sum()
+}
+
+/// The middle completion score, or `None` if no line is incomplete
+pub fn part_2(lines: &[String]) -> Option<usize> {
+    let scores: Vec<usize> = lines
+        .iter()
+        .map(|line| complete_line(line))
+        .filter(|completion_string| !completion_string.is_empty())
+        .map(score_completion_string)
+        .collect();
+    middle_score(scores)
+}
+
+fn middle_score(mut scores: Vec<usize>) -> Option<usize> {
+    scores.sort_unstable();
+    scores.get(scores.len().checked_sub(1)? / 2).copied()
+}
+
+/// Both answers from one pass over `reader`, holding one score per incomplete line rather
+/// than the lines themselves
+///
+/// Part 2 is `None` if no line is incomplete, as with `part_2`
+pub fn stream(reader: impl BufRead) -> Result<(usize, Option<usize>), StreamError> {
+    let mut illegal_characters = Counter::new();
+    let mut scores = Vec::new();
+    parse::for_each_line(10, reader, |line| {
+        let line = check_brackets(line)?;
+        illegal_characters.extend(find_first_illegal_character(line));
+        let completion_string = complete_line(line);
+        if !completion_string.is_empty() {
+            scores.push(score_completion_string(completion_string));
+        }
+        Ok(())
+    })?;
+    Ok((
+        score_illegal_characters(illegal_characters),
+        middle_score(scores),
+    ))
+}
+
+fn complete_line(line: &str) -> Vec<char> {
+    let pairs = build_character_pair_map();
+    let mut stack = Vec::new();
+
+    for c in line.chars() {
+        match c {
+            '{' | '(' | '[' | '<' => stack.push(c),
+            '}' | ')' | ']' | '>' => {
+                let opening_symbol = *pairs.get(&c).unwrap();
+                let paired_symbol = stack.pop();
+                if paired_symbol != Some(opening_symbol) {
+                    return Vec::new();
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    if stack.is_empty() {
+        return Vec::new();
+    }
+
+    let reverse_pairs: HashMap<char, char> = pairs.into_iter().map(|(k, v)| (v, k)).collect();
+
+    stack
+        .into_iter()
+        .rev()
+        .map(|ch| *reverse_pairs.get(&ch).unwrap())
+        .collect()
+}
+
+fn score_completion_string(characters: Vec<char>) -> usize {
+    let character_score: HashMap<char, usize> =
+        HashMap::from_iter([(')', 1), (']', 2), ('}', 3), ('>', 4)]);
+    characters
+        .iter()
+        .fold(0, |acc, ch| acc * 5 + character_score.get(ch).unwrap())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Self::Input) -> usize {
+        part_1(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> usize {
+        part_2(lines).expect("No incomplete lines")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_first_illegal_character() {
+        assert_eq!(
+            find_first_illegal_character("[({(<(())[]>[[{[]{<()<>>"),
+            None
+        );
+        assert_eq!(
+            find_first_illegal_character("{([(<{}[<>[]}>{[]{[(<()>"),
+            Some('}')
+        );
+        assert_eq!(
+            find_first_illegal_character("[[<[([]))<([[{}[[()]]]"),
+            Some(')')
+        );
+    }
+
+    #[test]
+    fn test_stream() {
+        let input = include_str!("../../fixtures/2021/day10.txt");
+        let lines = parse_input(input).unwrap();
+        assert_eq!(
+            stream(input.as_bytes()).unwrap(),
+            (part_1(&lines), part_2(&lines))
+        );
+    }
+
+    #[test]
+    fn test_no_incomplete_lines() {
+        assert_eq!(stream(&b""[..]).unwrap(), (0, None));
+        assert_eq!(part_2(&[]), None);
+
+        let input = "{([(<{}[<>[]}>{[]{[(<()>\n[]\n";
+        let lines = parse_input(input).unwrap();
+        assert_eq!(part_2(&lines), None);
+        assert_eq!(stream(input.as_bytes()).unwrap(), (1197, None));
+    }
+
+    #[test]
+    fn test_score_illegal_characters() {
+        assert_eq!(
+            score_illegal_characters(Counter::from([(')', 2), (']', 1)])),
+            63
+        )
+    }
+
+    #[test]
+    fn test_complete_line() {
+        // Incomplete line
+        assert_eq!(
+            complete_line("[({(<(())[]>[[{[]{<()<>>"),
+            ['}', '}', ']', ']', ')', '}', ')', ']']
+        );
+        // Corrupted line
+        assert_eq!(complete_line("{([(<{}[<>[]}>{[]{[(<()>"), [],)
+    }
+
+    #[test]
+    fn fn_score_completion_string() {
+        assert_eq!(
+            score_completion_string(vec!['}', '}', ']', ']', ')', '}', ')', ']']),
+            288957
+        );
+    }
+
+    #[test]
+    fn test_parse_input_rejects_other_characters() {
+        let error = parse_input("[({(<(())[]>[[{[]{<()<>>\n[(()[<x>])]").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+
+use crate::{
+    grid::{Grid, Position},
+    parse::ParseError,
+    solution::{Params, Solution},
+};
+
+pub fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_digits(11, input)
+}
+
+pub fn part_1(grid: &Grid<u32>) -> usize {
+    count_flashes(grid, 100)
+}
+
+/// The total number of flashes over `steps` steps
+pub fn count_flashes(grid: &Grid<u32>, steps: usize) -> usize {
+    let grid = grid.clone();
+    let (_final_grid, flashes) =
+        (0..steps).fold((grid, 0usize), |(mut grid, total_flashes), _step| {
+            let flashes = compute_flashes(&mut grid);
+            (grid, total_flashes + flashes)
+        });
+    flashes
+}
+
+pub fn part_2(grid: &Grid<u32>) -> usize {
+    let mut grid = grid.clone();
+    let mut steps_until_simultaneous_flash: usize = 0;
+
+    for i in 0.. {
+        let flashes = compute_flashes(&mut grid);
+        if flashes == grid.len() {
+            steps_until_simultaneous_flash = i + 1;
+            break;
+        }
+    }
+    steps_until_simultaneous_flash
+}
+
+fn compute_flashes(grid: &mut Grid<u32>) -> usize {
+    grid.values_mut().for_each(|energy| *energy += 1);
+    let mut stack: Vec<Position> = grid
+        .iter()
+        .filter(|&(_, &energy)| energy > 9)
+        .map(|(position, _)| position)
+        .collect();
+
+    let mut flashes = 0usize;
+    let mut has_flashed: HashSet<Position> = HashSet::new();
+
+    while let Some(position) = stack.pop() {
+        if !has_flashed.insert(position) {
+            // We might have put a point onto the stack, which since its addition has already flashed
+            continue;
+        }
+
+        flashes += 1;
+        grid[position] = 0;
+
+        let neighbours: Vec<Position> = grid.neighbours8(position).collect();
+        for neighbour in neighbours {
+            if grid[neighbour] != 0 {
+                grid[neighbour] += 1;
+                if grid[neighbour] > 9 {
+                    stack.push(neighbour);
+                }
+            };
+        }
+    }
+    flashes
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<u32>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+    const PARAMS: &'static [&'static str] = &["steps"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        part_1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        part_2(grid)
+    }
+
+    fn part1_with(grid: &Self::Input, params: &Params) -> usize {
+        count_flashes(grid, params.count_or("steps", 100))
+    }
+}
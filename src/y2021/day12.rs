@@ -1,33 +1,38 @@
 use std::collections::{HashMap, HashSet};
 
-use aoc_runner_derive::aoc;
+use crate::{
+    parse::{self, ParseError},
+    solution::Solution,
+};
 
 const START: &str = "start";
 const END: &str = "end";
 
-#[derive(Debug)]
-pub struct Graph<'a> {
-    edges: HashMap<&'a str, HashSet<&'a str>>,
+/// An undirected graph of caves, keyed by name
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Graph {
+    edges: HashMap<String, HashSet<String>>,
 }
 
 type VisitorCallback = fn(node: &str, visited: &HashMap<&str, u32>) -> bool;
 
-impl<'a> Graph<'a> {
-    fn new() -> Self {
+impl Graph {
+    pub fn new() -> Self {
         Graph {
             edges: HashMap::new(),
         }
     }
 
-    fn add_edge<'b>(&'b mut self, from: &'a str, to: &'a str) {
+    /// Connect `from` to `to`; edges are directed, so add both ways for a tunnel
+    pub fn add_edge(&mut self, from: &str, to: &str) {
         self.edges
-            .entry(from)
-            .or_insert_with(HashSet::new)
-            .insert(to);
+            .entry(from.to_string())
+            .or_default()
+            .insert(to.to_string());
     }
 
-    fn dfs(
-        &self,
+    fn dfs<'a>(
+        &'a self,
         current_edge: &'a str,
         visited: &mut HashMap<&'a str, u32>,
         path: &mut Vec<&'a str>,
@@ -76,25 +81,33 @@ impl<'a> Graph<'a> {
     }
 }
 
-pub fn parse_input(input: &str) -> Graph {
+pub fn parse_input(input: &str) -> Result<Graph, ParseError> {
     let mut graph = Graph::new();
-    for line in input.lines() {
-        let mut tokens = line.split('-');
-        let from = tokens.next().unwrap();
-        let to = tokens.next().unwrap();
+    for line in parse::lines(12, input) {
+        let (from, to) = line.split_once("-")?;
+        if from.is_empty() || to.is_empty() || to.contains('-') {
+            return Err(line.error("expected an edge of the form `from-to`"));
+        }
         graph.add_edge(from, to);
         graph.add_edge(to, from);
     }
-    graph
+    for cave in [START, END] {
+        if !graph.edges.contains_key(cave) {
+            return Err(parse::unexpected_end(
+                12,
+                input,
+                format!("expected a connection to the `{}` cave", cave),
+            ));
+        }
+    }
+    Ok(graph)
 }
 
 fn is_small_cave(cave: &str) -> bool {
     cave.chars().all(|c| c.is_lowercase())
 }
 
-#[aoc(day12, part1)]
-pub fn part_1(input: &str) -> usize {
-    let graph = parse_input(input);
+pub fn part_1(graph: &Graph) -> usize {
     graph.find_unique_paths(|node, visited| {
         if !is_small_cave(node) {
             return true;
@@ -104,9 +117,7 @@ pub fn part_1(input: &str) -> usize {
     })
 }
 
-#[aoc(day12, part2)]
-pub fn part_2(input: &str) -> usize {
-    let graph = parse_input(input);
+pub fn part_2(graph: &Graph) -> usize {
     graph.find_unique_paths(|node, visited| {
         if node == START {
             return visited.get(START) != Some(&1);
@@ -124,38 +135,37 @@ pub fn part_2(input: &str) -> usize {
     })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Day12;
 
-    const EXAMPLE: &str = "start-A
-start-b
-A-c
-A-b
-b-d
-A-end
-b-end";
-
-    const OTHER_EXAMPLE: &str = "dc-end
-HN-start
-start-kj
-dc-start
-dc-HN
-LN-dc
-HN-end
-kj-sa
-kj-HN
-kj-dc";
+impl Solution for Day12 {
+    type Input = Graph;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    #[test]
-    fn test_part_1() {
-        assert_eq!(part_1(EXAMPLE), 10);
-        assert_eq!(part_1(OTHER_EXAMPLE), 19);
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
     }
 
+    fn part1(graph: &Self::Input) -> usize {
+        part_1(graph)
+    }
+
+    fn part2(graph: &Self::Input) -> usize {
+        part_2(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
     #[test]
-    fn test_part_2() {
-        assert_eq!(part_2(EXAMPLE), 36);
-        assert_eq!(part_2(OTHER_EXAMPLE), 103);
+    fn test_parse_input_requires_start_and_end() {
+        assert!(parse_input("start-A\nA-b").is_err());
+        assert_eq!(parse_input("start-A\nA_end").unwrap_err().line, 2);
     }
 }
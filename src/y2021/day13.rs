@@ -0,0 +1,182 @@
+use crate::{
+    answer::{Answer, Image},
+    grid,
+    ocr::{self, OcrError},
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+/// Dots on the transparent paper
+pub type Grid = grid::Grid<bool>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+/// A fold along the line `x=line` or `y=line`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fold {
+    pub axis: Axis,
+    pub line: u32,
+}
+
+impl Fold {
+    fn perform_on(&self, grid: &Grid) -> Grid {
+        let (columns, rows) = (grid.width(), grid.height());
+
+        // Overlay the half past the fold, mirrored, on the half before it
+        let (width, height, mirrored) = match self.axis {
+            Axis::X => {
+                // Fold left
+                assert!(self.line * 2 + 1 == columns as u32);
+                (columns / 2, rows, grid.flip_horizontal())
+            }
+            Axis::Y => {
+                // Fold up
+                assert!(self.line * 2 + 1 == rows as u32);
+                (columns, rows / 2, grid.flip_vertical())
+            }
+        };
+        Grid::from_fn(width, height, |position| {
+            grid[position] || mirrored[position]
+        })
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Grid, Vec<Fold>), ParseError> {
+    let mut lines = parse::lines(13, input);
+
+    let mut coordinates: Vec<(usize, usize)> = vec![];
+    let mut max_x = 0;
+    let mut max_y = 0;
+
+    loop {
+        match lines.next() {
+            Some(line) => match line.text {
+                "" => break,
+                _ => {
+                    let (x, y) = line.split_once(",")?;
+                    let x = line.parse(x)?;
+                    let y = line.parse(y)?;
+                    if y > max_y {
+                        max_y = y
+                    }
+                    if x > max_x {
+                        max_x = x
+                    }
+                    coordinates.push((x, y))
+                }
+            },
+            None => {
+                return Err(parse::unexpected_end(
+                    13,
+                    input,
+                    "expected a blank line followed by the folds",
+                ))
+            }
+        }
+    }
+
+    let mut grid = Grid::new(max_x + 1, max_y + 1, false);
+
+    coordinates.into_iter().for_each(|dot| grid[dot] = true);
+
+    let folds: Vec<Fold> = lines
+        .map(|l| {
+            let instruction = l
+                .text
+                .strip_prefix("fold along ")
+                .ok_or_else(|| l.error("expected a line of the form `fold along axis=line`"))?;
+            let (axis, line) = instruction
+                .split_once('=')
+                .ok_or_else(|| l.error_at(instruction, "expected `axis=line`"))?;
+            let axis = match axis {
+                "x" => Axis::X,
+                "y" => Axis::Y,
+                _ => return Err(l.error_at(axis, "expected cartesian axis `x` or `y`")),
+            };
+            let line = l.parse(line)?;
+            Ok(Fold { axis, line })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((grid, folds))
+}
+
+pub fn part_1((grid, folds): &(Grid, Vec<Fold>)) -> usize {
+    let first_fold = &folds[0];
+    let new_grid = first_fold.perform_on(grid);
+    new_grid.values().filter(|&&is_dot| is_dot).count()
+}
+
+pub fn part_2((grid, folds): &(Grid, Vec<Fold>)) -> Image {
+    let final_grid: Grid = folds
+        .iter()
+        .fold(grid.clone(), |acc, fold| fold.perform_on(&acc));
+
+    Image::from(&final_grid)
+}
+
+/// The paper after every fold, and the code read from it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reading {
+    pub image: Image,
+    pub code: Result<String, OcrError>,
+}
+
+/// Make every fold, then read the code the dots draw
+pub fn read_code(instructions: &(Grid, Vec<Fold>)) -> Reading {
+    let image = part_2(instructions);
+    let code = ocr::recognize(&image);
+    Reading { image, code }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Grid, Vec<Fold>);
+    type Output1 = usize;
+    type Output2 = Answer;
+
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Transparent Origami";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> usize {
+        part_1(instructions)
+    }
+
+    /// The letters, or the dots themselves if they aren't letters we know, in which case
+    /// `read_code` says why
+    fn part2(instructions: &Self::Input) -> Answer {
+        match read_code(instructions) {
+            Reading { code: Ok(code), .. } => Answer::from(code),
+            Reading { image, .. } => Answer::from(image),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_code_keeps_the_reason() {
+        let instructions = parse_input(include_str!("../../fixtures/2021/day13.txt")).unwrap();
+        let reading = read_code(&instructions);
+        assert_eq!(reading.image, part_2(&instructions));
+        assert!(matches!(reading.code, Err(OcrError::Size { .. })));
+    }
+
+    #[test]
+    fn test_parse_input_reports_bad_fold() {
+        let error = parse_input("6,10\n0,14\n\nfold along z=7").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 12));
+    }
+}
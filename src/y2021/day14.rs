@@ -0,0 +1,162 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::str;
+
+use crate::{
+    counter::Counter,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+/// Pair insertion rules, e.g. `CH -> B`
+pub type Rules = HashMap<(char, char), char>;
+
+pub fn parse_input(input: &str) -> Result<(Vec<char>, Rules), ParseError> {
+    let mut lines = parse::lines(14, input);
+
+    let template: Vec<char> = lines
+        .next()
+        .ok_or_else(|| parse::unexpected_end(14, input, "expected a polymer template"))?
+        .text
+        .chars()
+        .collect();
+    if template.is_empty() {
+        return Err(parse::unexpected_end(
+            14,
+            input,
+            "expected a polymer template",
+        ));
+    }
+
+    if let Some(separator) = lines.next() {
+        if !separator.text.is_empty() {
+            return Err(separator.error("expected a blank line after the template"));
+        }
+    }
+
+    let rules: Rules = lines
+        .map(|l| {
+            let (pair, element) = l.split_once(" -> ")?;
+            let mut pair_chars = pair.chars();
+            let mut element_chars = element.chars();
+            match (
+                pair_chars.next(),
+                pair_chars.next(),
+                pair_chars.next(),
+                element_chars.next(),
+                element_chars.next(),
+            ) {
+                (Some(a), Some(b), None, Some(element), None) => Ok(((a, b), element)),
+                _ => Err(l.error("expected a rule of the form `AB -> C`")),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((template, rules))
+}
+
+fn step(current_pairs: Counter<(char, char)>, rules: &Rules) -> Counter<(char, char)> {
+    let mut pairs = Counter::new();
+
+    current_pairs.into_iter().for_each(|(pair, count)| {
+        let (a, b) = pair;
+        match rules.get(&pair) {
+            Some(&element) => {
+                pairs.add_n((a, element), count);
+                pairs.add_n((element, b), count);
+            }
+            // Nothing is inserted between a pair with no rule
+            None => pairs.add_n(pair, count),
+        }
+    });
+
+    pairs
+}
+
+fn evaluate(pairs: Counter<(char, char)>, last_element: char) -> u128 {
+    let mut counters: Counter<char> = Counter::new();
+
+    // If we only use the first item in the pair for each pair, we avoid double counting
+    // However, we will need to increment the last element in the (original) template,
+    // because we will undercount by 1 (as it won't be the "first" element in any pair)
+
+    pairs
+        .into_iter()
+        .for_each(|((a, _b), count)| counters.add_n(a, count));
+
+    counters.add(last_element);
+
+    match (counters.most_common(), counters.least_common()) {
+        (Some((_, max)), Some((_, min))) => u128::from(max - min),
+        _ => unreachable!(),
+    }
+}
+
+fn load_initial_pairs(template: &[char]) -> Counter<(char, char)> {
+    template.iter().copied().tuple_windows().collect()
+}
+
+pub fn part_1((template, rules): &(Vec<char>, Rules)) -> u128 {
+    let last_element = template[template.len() - 1];
+
+    let final_pairs = (0..10).fold(load_initial_pairs(template), |acc, _step| step(acc, rules));
+
+    evaluate(final_pairs, last_element)
+}
+
+pub fn part_2((template, rules): &(Vec<char>, Rules)) -> u128 {
+    let last_element = template[template.len() - 1];
+
+    let final_pairs = (0..40).fold(load_initial_pairs(template), |acc, _step| step(acc, rules));
+
+    evaluate(final_pairs, last_element)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Vec<char>, Rules);
+    type Output1 = u128;
+    type Output2 = u128;
+
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> u128 {
+        part_1(instructions)
+    }
+
+    fn part2(instructions: &Self::Input) -> u128 {
+        part_2(instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input_reports_bad_rule() {
+        let error = parse_input("NNCB\n\nCH -> B\nHHH -> N").unwrap_err();
+        assert_eq!(error.line, 4);
+    }
+
+    #[test]
+    fn test_pairs_without_rules_stay_unchanged() {
+        let instructions = parse_input("NNCB\n\nCH -> B").unwrap();
+        assert_eq!(part_1(&instructions), 1);
+        assert_eq!(part_2(&instructions), 1);
+    }
+
+    #[test]
+    fn test_parse_input_reports_missing_blank_line() {
+        let error = parse_input("NNCB\nCH -> B\nHH -> N").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.message, "expected a blank line after the template");
+    }
+}
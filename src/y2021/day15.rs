@@ -0,0 +1,105 @@
+use crate::{
+    grid::Grid,
+    parse::ParseError,
+    pathfinding::{self, Successors},
+    solution::Solution,
+};
+
+/// Risks run from 1 to 9, so a 0 is rejected like any other unexpected character
+pub fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(15, input, |c| c.to_digit(10).filter(|&risk| risk > 0))
+}
+
+/// The lowest total risk of any path from the top left to the bottom right
+fn lowest_risk(grid: &Grid<u32>) -> u32 {
+    let end = grid.last_position().expect("grids aren't empty");
+    // Entering a position costs its risk
+    let cavern = Successors::new(|&position| {
+        grid.neighbours4(position)
+            .map(|neighbour| (neighbour, u64::from(grid[neighbour])))
+            .collect()
+    });
+    // Every step costs at least the lowest risk, so the manhattan distance times that never
+    // overestimates, even for grids not from `parse_input` with risks of 0
+    let lowest = u64::from(grid.values().copied().min().unwrap_or(0));
+    let heuristic =
+        |&(x, y): &(usize, usize)| (end.0.abs_diff(x) + end.1.abs_diff(y)) as u64 * lowest;
+
+    let path = pathfinding::astar(&cavern, (0, 0), |&position| position == end, heuristic)
+        .expect("the end is always reachable");
+    path.cost as u32
+}
+
+pub fn part_1(grid: &Grid<u32>) -> u32 {
+    lowest_risk(grid)
+}
+
+pub fn part_2(grid: &Grid<u32>) -> u32 {
+    let (width, height) = (grid.width(), grid.height());
+    let larger_grid = Grid::from_fn(width * 5, height * 5, |(x, y)| {
+        let increment = (x / width + y / height) as u32;
+        // Risks wrap around from 9 back to 1
+        (grid[(x % width, y % height)] + increment - 1) % 9 + 1
+    });
+
+    lowest_risk(&larger_grid)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<u32>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Chiton";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> u32 {
+        part_1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> u32 {
+        part_2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dijkstra_risk(grid: &Grid<u32>) -> u32 {
+        let cavern = Successors::new(|&position| {
+            grid.neighbours4(position)
+                .map(|neighbour| (neighbour, u64::from(grid[neighbour])))
+                .collect()
+        });
+        let end = grid.last_position().unwrap();
+        let path = pathfinding::dijkstra(&cavern, (0, 0), |&position| position == end).unwrap();
+        path.cost as u32
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let example = parse_input(include_str!("../../fixtures/2021/day15.txt")).unwrap();
+        // The manhattan distance alone overestimates here, and A* would find a path of risk 4
+        let zeros =
+            Grid::from_rows(vec![vec![0, 1, 0, 0], vec![0, 1, 1, 1], vec![1, 0, 9, 1]]).unwrap();
+        assert_eq!(lowest_risk(&zeros), 3);
+        for grid in [example, zeros] {
+            assert_eq!(lowest_risk(&grid), dijkstra_risk(&grid));
+        }
+    }
+
+    #[test]
+    fn test_parse_input_rejects_zero_risk() {
+        let error = parse_input("116\n109\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "unexpected character '0'");
+    }
+}
@@ -0,0 +1,590 @@
+use nom::{
+    branch::alt,
+    bytes::streaming::tag,
+    character::complete::digit1,
+    combinator::{map, map_res},
+    sequence::tuple,
+    IResult,
+};
+
+use std::{error::Error, fmt, io::BufRead};
+
+use crate::{
+    parse::{self, Line, ParseError, StreamError},
+    solution::Solution,
+};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Direction {
+    Forward,
+    Up,
+    Down,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Command {
+    pub direction: Direction,
+    pub units: u32,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Direction::Forward => "forward",
+            Direction::Up => "up",
+            Direction::Down => "down",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Written as it is in the puzzle input, e.g. `forward 5`
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.direction, self.units)
+    }
+}
+
+pub fn parse_command(input: &str) -> IResult<&str, Command> {
+    let (input, (direction, _, units)) = tuple((parse_direction, tag(" "), parse_units))(input)?;
+    Ok((input, Command { direction, units }))
+}
+
+fn parse_direction(input: &str) -> IResult<&str, Direction> {
+    alt((
+        map(tag("forward"), |_| Direction::Forward),
+        map(tag("up"), |_| Direction::Up),
+        map(tag("down"), |_| Direction::Down),
+    ))(input)
+}
+
+fn parse_units(input: &str) -> IResult<&str, u32> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn read_command(line: Line) -> Result<Command, ParseError> {
+    match parse_command(line.text) {
+        Ok(("", command)) => Ok(command),
+        Ok((rest, _)) => Err(line.error_at(rest, "unexpected trailing characters")),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(line.error_at(
+            e.input,
+            "expected `forward`, `up` or `down` followed by a number of units",
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(line.error("incomplete command")),
+    }
+}
+
+pub fn read_commands(input: &str) -> Result<Vec<Command>, ParseError> {
+    parse::lines(2, input).map(read_command).collect()
+}
+
+/// How commands move the submarine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Steering {
+    /// Part 1: `up` and `down` change the depth directly
+    Plain,
+    /// Part 2: `up` and `down` change the aim, and `forward` dives along it
+    Aimed,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+    /// Always 0 when steering plainly
+    pub aim: i64,
+}
+
+impl Position {
+    /// The puzzle's answer, which can't overflow
+    pub fn product(&self) -> i128 {
+        i128::from(self.horizontal) * i128::from(self.depth)
+    }
+}
+
+/// Why the submarine couldn't carry out a command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmarineError {
+    /// The position went outside what an `i64` can hold
+    Overflow { index: usize, command: Command },
+    /// The depth went below zero, which is only checked in strict mode
+    AboveSurface {
+        index: usize,
+        command: Command,
+        depth: i64,
+    },
+}
+
+impl fmt::Display for SubmarineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmarineError::Overflow { index, command } => {
+                write!(f, "command {} (`{}`) overflowed", index, command)
+            }
+            SubmarineError::AboveSurface {
+                index,
+                command,
+                depth,
+            } => write!(
+                f,
+                "command {} (`{}`) took the submarine above the surface, to depth {}",
+                index, command, depth
+            ),
+        }
+    }
+}
+
+impl Error for SubmarineError {}
+
+/// Carries out commands one at a time, counting them so errors can say which went wrong
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submarine {
+    steering: Steering,
+    strict: bool,
+    position: Position,
+    steps: usize,
+}
+
+impl Submarine {
+    pub fn new(steering: Steering) -> Self {
+        Submarine {
+            steering,
+            strict: false,
+            position: Position::default(),
+            steps: 0,
+        }
+    }
+
+    /// Refuse to go above the surface, as well as to overflow
+    pub fn strict(self) -> Self {
+        Submarine {
+            strict: true,
+            ..self
+        }
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// How many commands have been carried out
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Carry out `command`, returning where it took us
+    ///
+    /// On failure the submarine stays where it was, and the error names the command by its
+    /// index among every command this submarine has been given
+    pub fn step(&mut self, command: &Command) -> Result<Position, SubmarineError> {
+        let index = self.steps;
+        let overflow = SubmarineError::Overflow {
+            index,
+            command: *command,
+        };
+        let Position {
+            horizontal,
+            depth,
+            aim,
+        } = self.position;
+        let units = i64::from(command.units);
+
+        let next = match (self.steering, command.direction) {
+            (Steering::Plain, Direction::Forward) => {
+                horizontal.checked_add(units).map(|horizontal| Position {
+                    horizontal,
+                    ..self.position
+                })
+            }
+            (Steering::Plain, Direction::Up) => depth.checked_sub(units).map(|depth| Position {
+                depth,
+                ..self.position
+            }),
+            (Steering::Plain, Direction::Down) => depth.checked_add(units).map(|depth| Position {
+                depth,
+                ..self.position
+            }),
+            (Steering::Aimed, Direction::Up) => aim.checked_sub(units).map(|aim| Position {
+                aim,
+                ..self.position
+            }),
+            (Steering::Aimed, Direction::Down) => aim.checked_add(units).map(|aim| Position {
+                aim,
+                ..self.position
+            }),
+            (Steering::Aimed, Direction::Forward) => horizontal
+                .checked_add(units)
+                .zip(
+                    aim.checked_mul(units)
+                        .and_then(|dive| depth.checked_add(dive)),
+                )
+                .map(|(horizontal, depth)| Position {
+                    horizontal,
+                    depth,
+                    aim,
+                }),
+        }
+        .ok_or(overflow)?;
+
+        if self.strict && next.depth < 0 {
+            return Err(SubmarineError::AboveSurface {
+                index,
+                command: *command,
+                depth: next.depth,
+            });
+        }
+        self.position = next;
+        self.steps += 1;
+        Ok(next)
+    }
+
+    /// Carry out every command, returning where we end up
+    pub fn run<'a>(
+        &mut self,
+        commands: impl IntoIterator<Item = &'a Command>,
+    ) -> Result<Position, SubmarineError> {
+        for command in commands {
+            self.step(command)?;
+        }
+        Ok(self.position)
+    }
+
+    /// Carry out every command, returning where each one took us
+    pub fn trajectory<'a>(
+        &mut self,
+        commands: impl IntoIterator<Item = &'a Command>,
+    ) -> Result<Vec<Position>, SubmarineError> {
+        commands
+            .into_iter()
+            .map(|command| self.step(command))
+            .collect()
+    }
+}
+
+fn navigate(commands: &[Command], steering: Steering) -> i128 {
+    match Submarine::new(steering).run(commands) {
+        Ok(position) => position.product(),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+pub fn part1(commands: &[Command]) -> i128 {
+    navigate(commands, Steering::Plain)
+}
+
+pub fn part2(commands: &[Command]) -> i128 {
+    navigate(commands, Steering::Aimed)
+}
+
+/// Both answers from one pass over `reader`, holding only the current positions
+pub fn stream(reader: impl BufRead) -> Result<(i128, i128), StreamError> {
+    let mut plain = Submarine::new(Steering::Plain);
+    let mut aimed = Submarine::new(Steering::Aimed);
+    parse::for_each_line(2, reader, |line| {
+        let command = read_command(line)?;
+        for submarine in [&mut plain, &mut aimed] {
+            submarine
+                .step(&command)
+                .map_err(|e| StreamError::solve(line, e))?;
+        }
+        Ok(())
+    })?;
+    Ok((plain.position().product(), aimed.position().product()))
+}
+
+/// Commands which take an aimed submarine from the surface to `horizontal` and `depth`, or
+/// `None` if nothing can: it can't go backwards, or change depth without going forward
+///
+/// Every route is replayed by a `Submarine` before it's returned, giving `None` if it misses
+///
+/// Uses as few commands as possible, unless some move is too big for one command's units and
+/// has to be split up
+pub fn plan_route(horizontal: i64, depth: i64) -> Option<Vec<Command>> {
+    let mut route = Vec::new();
+    match (horizontal, depth) {
+        (0, 0) => {}
+        (h, _) if h <= 0 => return None,
+        (h, 0) => repeat(&mut route, Direction::Forward, h.unsigned_abs()),
+        // Aim once, then go all the way
+        (h, d) if d % h == 0 => {
+            aim(&mut route, d / h);
+            repeat(&mut route, Direction::Forward, h.unsigned_abs());
+        }
+        // Go most of the way level, then aim and finish with a stretch that divides the depth
+        (h, d) => {
+            let last = longest_stretch(h, d);
+            repeat(&mut route, Direction::Forward, (h - last).unsigned_abs());
+            aim(&mut route, d / last);
+            repeat(&mut route, Direction::Forward, last.unsigned_abs());
+        }
+    }
+    reaches(&route, horizontal, depth).then_some(route)
+}
+
+/// The longest stretch shorter than `horizontal` whose length divides `depth`, keeping the aim
+/// small
+///
+/// Only divisors `i` up to `SEARCH_LIMIT` and the square root of `depth` are tried, each with
+/// its cofactor `depth / i`. For depths above `SEARCH_LIMIT` squared this can miss the longest
+/// stretch, but never falls below 1, which divides everything
+fn longest_stretch(horizontal: i64, depth: i64) -> i64 {
+    const SEARCH_LIMIT: u64 = 1 << 16;
+    let depth = depth.unsigned_abs();
+    let mut longest = 1;
+    for i in (1..=SEARCH_LIMIT).take_while(|i| i * i <= depth) {
+        if depth.is_multiple_of(i) {
+            for stretch in [i, depth / i] {
+                if stretch < horizontal.unsigned_abs() {
+                    longest = longest.max(stretch);
+                }
+            }
+        }
+    }
+    longest as i64
+}
+
+fn aim(route: &mut Vec<Command>, change: i64) {
+    let direction = match change < 0 {
+        true => Direction::Up,
+        false => Direction::Down,
+    };
+    repeat(route, direction, change.unsigned_abs());
+}
+
+/// Move `units` in `direction`, in as many commands as it takes
+fn repeat(route: &mut Vec<Command>, direction: Direction, mut units: u64) {
+    while units > 0 {
+        let step = units.min(u32::MAX.into());
+        route.push(Command {
+            direction,
+            units: step as u32,
+        });
+        units -= step;
+    }
+}
+
+/// Whether `commands` take an aimed submarine from the surface to `horizontal` and `depth`
+pub fn reaches(commands: &[Command], horizontal: i64, depth: i64) -> bool {
+    match Submarine::new(Steering::Aimed).run(commands) {
+        Ok(end) => end.horizontal == horizontal && end.depth == depth,
+        Err(_) => false,
+    }
+}
+
+/// One command per line, as `read_commands` reads them
+pub fn write_commands(commands: &[Command]) -> String {
+    commands.iter().map(|c| format!("{}\n", c)).collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Command>;
+    type Output1 = i128;
+    type Output2 = i128;
+
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Dive!";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_commands(input)
+    }
+
+    fn part1(commands: &Self::Input) -> i128 {
+        part1(commands)
+    }
+
+    fn part2(commands: &Self::Input) -> i128 {
+        part2(commands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream() {
+        let input = include_str!("../../fixtures/2021/day2.txt");
+        let commands = read_commands(input).unwrap();
+        assert_eq!(
+            stream(input.as_bytes()).unwrap(),
+            (part1(&commands), part2(&commands))
+        );
+        let crlf = input.replace('\n', "\r\n");
+        assert_eq!(
+            stream(crlf.as_bytes()).unwrap(),
+            (part1(&commands), part2(&commands))
+        );
+    }
+
+    #[test]
+    fn test_stream_reports_overflow() {
+        let input = "down 4294967295\n".repeat(3) + "forward 4294967295\n";
+        match stream(input.as_bytes()) {
+            Err(StreamError::Solve { day, line, error }) => {
+                assert_eq!((day, line), (2, 4));
+                assert!(matches!(
+                    error.downcast_ref(),
+                    Some(SubmarineError::Overflow { index: 3, .. })
+                ));
+            }
+            other => panic!("expected an overflow, got {:?}", other),
+        }
+    }
+
+    fn command(direction: Direction, units: u32) -> Command {
+        Command { direction, units }
+    }
+
+    #[test]
+    fn test_trajectory() {
+        let commands = read_commands(include_str!("../../fixtures/2021/day2.txt")).unwrap();
+        let depths = |steering| -> Vec<i64> {
+            let trajectory = Submarine::new(steering).trajectory(&commands).unwrap();
+            trajectory.iter().map(|p| p.depth).collect()
+        };
+        assert_eq!(depths(Steering::Plain), vec![0, 5, 5, 2, 10, 10]);
+        assert_eq!(depths(Steering::Aimed), vec![0, 0, 40, 40, 40, 60]);
+
+        let mut submarine = Submarine::new(Steering::Aimed);
+        let end = submarine.run(&commands).unwrap();
+        assert_eq!(
+            end,
+            Position {
+                horizontal: 15,
+                depth: 60,
+                aim: 10
+            }
+        );
+        assert_eq!(submarine.steps(), 6);
+    }
+
+    #[test]
+    fn test_above_the_surface() {
+        let commands = [command(Direction::Down, 2), command(Direction::Up, 3)];
+        assert_eq!(
+            Submarine::new(Steering::Plain)
+                .run(&commands)
+                .unwrap()
+                .depth,
+            -1
+        );
+
+        let mut submarine = Submarine::new(Steering::Plain).strict();
+        assert_eq!(
+            submarine.run(&commands),
+            Err(SubmarineError::AboveSurface {
+                index: 1,
+                command: commands[1],
+                depth: -1
+            })
+        );
+        // The failed command wasn't carried out
+        assert_eq!(submarine.position().depth, 2);
+        assert_eq!(submarine.steps(), 1);
+
+        // Aiming up is fine, so long as we don't surface
+        let commands = [command(Direction::Up, 1), command(Direction::Forward, 1)];
+        let error = Submarine::new(Steering::Aimed)
+            .strict()
+            .run(&commands)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "command 1 (`forward 1`) took the submarine above the surface, to depth -1"
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut submarine = Submarine::new(Steering::Aimed);
+        let down = command(Direction::Down, u32::MAX);
+        let forward = command(Direction::Forward, u32::MAX);
+        submarine.run(&[down, down, down]).unwrap();
+        assert_eq!(
+            submarine.run(&[forward, forward]),
+            Err(SubmarineError::Overflow {
+                index: 3,
+                command: forward
+            })
+        );
+    }
+
+    #[test]
+    fn test_plan_route() {
+        let route = |h, d| write_commands(&plan_route(h, d).unwrap());
+        assert_eq!(route(0, 0), "");
+        assert_eq!(route(7, 0), "forward 7\n");
+        assert_eq!(route(15, 60), "down 4\nforward 15\n");
+        assert_eq!(route(15, -30), "up 2\nforward 15\n");
+        assert_eq!(route(10, 7), "forward 3\ndown 1\nforward 7\n");
+        assert_eq!(route(5, 14), "forward 3\ndown 7\nforward 2\n");
+        assert_eq!(plan_route(-1, 0), None);
+        assert_eq!(plan_route(0, 5), None);
+
+        for h in 0..30 {
+            for d in -60..60 {
+                if let Some(route) = plan_route(h, d) {
+                    assert!(route.len() <= 3);
+                    assert!(reaches(&route, h, d), "{} {}: {:?}", h, d, route);
+                    assert_eq!(read_commands(&write_commands(&route)).unwrap(), route);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_plan_route_splits_big_moves() {
+        let far = i64::from(u32::MAX) * 2 + 1;
+        let route = plan_route(far, far).unwrap();
+        assert_eq!(route.len(), 4);
+        assert!(reaches(&route, far, far));
+
+        let route = plan_route(far, -1).unwrap();
+        assert_eq!(route.len(), 4);
+        assert!(reaches(&route, far, -1));
+    }
+
+    #[test]
+    fn test_command_display() {
+        for text in ["forward 5", "up 3", "down 12"] {
+            let (_, command) = parse_command(text).unwrap();
+            assert_eq!(command.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn test_read_commands_reports_bad_lines() {
+        let error = read_commands("forward 5\nbackward 2").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = read_commands("forward 5\nup 3x").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(
+            parse_command("forward 5"),
+            Ok((
+                "",
+                Command {
+                    direction: Direction::Forward,
+                    units: 5
+                }
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_direction() {
+        assert_eq!(parse_direction("up"), Ok(("", Direction::Up)));
+    }
+
+    #[test]
+    fn test_parse_units() {
+        assert_eq!(parse_units("5"), Ok(("", 5)));
+        assert_eq!(parse_units("100"), Ok(("", 100)))
+    }
+}
@@ -0,0 +1,679 @@
+use std::{error::Error, fmt, io::BufRead};
+
+use crate::{
+    answer::Answer,
+    parse::{self, Line, ParseError, StreamError},
+    solution::Solution,
+};
+
+/// The widest numbers a report can hold
+pub const MAX_WIDTH: u32 = u128::BITS;
+
+/// Why a value couldn't be stored in a `WeightedTrie`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrieError {
+    TooLong { height: usize, length: usize },
+    InvalidDigit { digit: char, radix: u32 },
+}
+
+impl fmt::Display for TrieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrieError::TooLong { height, length } => write!(
+                f,
+                "Trie was initialized to {} height, requires {} height",
+                height, length
+            ),
+            TrieError::InvalidDigit { digit, radix } => {
+                write!(f, "{:?} is not a base {} digit", digit, radix)
+            }
+        }
+    }
+}
+
+impl Error for TrieError {}
+
+#[derive(Debug, Default, Clone)]
+struct Node {
+    /// Values stored at or below this node
+    count: usize,
+    /// Values which end at this node
+    ends: usize,
+    /// Sorted by digit, holding only the digits some value uses
+    children: Vec<(u32, usize)>,
+}
+
+impl Node {
+    fn child(&self, digit: u32) -> Option<usize> {
+        self.children
+            .binary_search_by_key(&digit, |&(d, _)| d)
+            .ok()
+            .map(|i| self.children[i].1)
+    }
+}
+
+/// Counts of strings of digits, up to `height` long, shared along their common prefixes
+///
+/// Nodes are only allocated for prefixes some stored value has, and are reused once every
+/// value under them is removed
+#[derive(Debug, Clone)]
+pub struct WeightedTrie {
+    pub height: usize,
+    pub radix: u32,
+    nodes: Vec<Node>,
+    free: Vec<usize>,
+}
+
+impl WeightedTrie {
+    /// A trie of binary strings
+    pub fn new(height: u32) -> Self {
+        Self::with_radix(height, 2)
+    }
+
+    /// A trie of strings of base `radix` digits, as `char::to_digit` reads them
+    pub fn with_radix(height: u32, radix: u32) -> Self {
+        if height == 0 {
+            panic!("Must be positive height")
+        }
+        assert!((2..=36).contains(&radix), "Radix must be from 2 to 36");
+
+        Self {
+            height: height as usize,
+            radix,
+            nodes: vec![Node::default()],
+            free: Vec::new(),
+        }
+    }
+
+    /// How many values are stored, counting repeats
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn digits(&self, input: &str) -> Result<Vec<u32>, TrieError> {
+        let digits = input
+            .chars()
+            .map(|digit| {
+                digit.to_digit(self.radix).ok_or(TrieError::InvalidDigit {
+                    digit,
+                    radix: self.radix,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if digits.len() > self.height {
+            return Err(TrieError::TooLong {
+                height: self.height,
+                length: digits.len(),
+            });
+        }
+        Ok(digits)
+    }
+
+    /// The node `digits` leads to, if any value has them as a prefix
+    fn find(&self, digits: impl IntoIterator<Item = u32>) -> Option<usize> {
+        digits
+            .into_iter()
+            .try_fold(0, |node, digit| self.nodes[node].child(digit))
+    }
+
+    pub fn insert(&mut self, input: &str) -> Result<(), TrieError> {
+        let digits = self.digits(input)?;
+        let mut node = 0;
+        self.nodes[node].count += 1;
+        for digit in digits {
+            node = match self.nodes[node]
+                .children
+                .binary_search_by_key(&digit, |&(d, _)| d)
+            {
+                Ok(i) => self.nodes[node].children[i].1,
+                Err(i) => {
+                    let child = self.allocate();
+                    self.nodes[node].children.insert(i, (digit, child));
+                    child
+                }
+            };
+            self.nodes[node].count += 1;
+        }
+        self.nodes[node].ends += 1;
+        Ok(())
+    }
+
+    fn allocate(&mut self) -> usize {
+        match self.free.pop() {
+            Some(node) => node,
+            None => {
+                self.nodes.push(Node::default());
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Remove one copy of `input`, returning whether there was one to remove
+    pub fn remove(&mut self, input: &str) -> Result<bool, TrieError> {
+        let digits = self.digits(input)?;
+        let mut path = vec![0];
+        for &digit in &digits {
+            match self.nodes[path[path.len() - 1]].child(digit) {
+                Some(child) => path.push(child),
+                None => return Ok(false),
+            }
+        }
+        let last = path[path.len() - 1];
+        if self.nodes[last].ends == 0 {
+            return Ok(false);
+        }
+        self.nodes[last].ends -= 1;
+
+        for (depth, &node) in path.iter().enumerate() {
+            self.nodes[node].count -= 1;
+            // Nothing is left below here, so unlink it and free the nodes for reuse
+            if self.nodes[node].count == 0 && depth > 0 {
+                let parent = &mut self.nodes[path[depth - 1]];
+                parent.children.retain(|&(_, child)| child != node);
+                for &freed in &path[depth..] {
+                    self.nodes[freed] = Node::default();
+                    self.free.push(freed);
+                }
+                break;
+            }
+        }
+        Ok(true)
+    }
+
+    /// How many values start with `prefix`, counting repeats
+    ///
+    /// A prefix which no value could have, being too long or having other digits, has none
+    pub fn count_prefix(&self, prefix: &str) -> usize {
+        self.digits(prefix)
+            .ok()
+            .and_then(|digits| self.find(digits))
+            .map_or(0, |node| self.nodes[node].count)
+    }
+
+    /// How many copies of `input` are stored
+    pub fn count(&self, input: &str) -> usize {
+        self.digits(input)
+            .ok()
+            .and_then(|digits| self.find(digits))
+            .map_or(0, |node| self.nodes[node].ends)
+    }
+
+    /// The distinct values which start with `prefix` and how many of each are stored, in digit
+    /// order with each value before the longer ones it's a prefix of
+    pub fn values_under(&self, prefix: &str) -> Values<'_> {
+        let start = self.digits(prefix).ok().and_then(|digits| {
+            let node = self.find(digits.iter().copied())?;
+            Some((node, digits.into_iter().map(|d| self.digit(d)).collect()))
+        });
+        Values {
+            trie: self,
+            stack: start.into_iter().collect(),
+        }
+    }
+
+    /// How `digit` is written in values the trie gives back
+    fn digit(&self, digit: u32) -> char {
+        char::from_digit(digit, self.radix).unwrap()
+    }
+}
+
+/// Iterator over stored values and their counts, from `WeightedTrie::values_under`
+pub struct Values<'a> {
+    trie: &'a WeightedTrie,
+    stack: Vec<(usize, String)>,
+}
+
+impl Iterator for Values<'_> {
+    type Item = (String, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, value)) = self.stack.pop() {
+            let Node { ends, children, .. } = &self.trie.nodes[node];
+            for &(digit, child) in children.iter().rev() {
+                let mut value = value.clone();
+                value.push(self.trie.digit(digit));
+                self.stack.push((child, value));
+            }
+            if *ends > 0 {
+                return Some((value, *ends));
+            }
+        }
+        None
+    }
+}
+
+/// Every number in the diagnostic report, with the bits of each line read into an integer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// How many bits every line has
+    pub width: u32,
+    pub numbers: Vec<u128>,
+}
+
+impl Report {
+    /// The bits a number can have set
+    pub fn mask(&self) -> u128 {
+        mask(self.width)
+    }
+}
+
+fn mask(width: u32) -> u128 {
+    match width {
+        MAX_WIDTH => u128::MAX,
+        width => (1 << width) - 1,
+    }
+}
+
+/// Read `line` as a binary number of the same width as the rest
+fn read_number(line: Line, width: &mut Option<u32>) -> Result<u128, ParseError> {
+    if let Some((i, c)) = line
+        .text
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !matches!(c, '0' | '1'))
+    {
+        return Err(line.error_at_column(i + 1, format!("expected a binary digit, found {:?}", c)));
+    }
+    let bits = line.text.len();
+    if bits == 0 {
+        return Err(line.error("expected a binary number"));
+    }
+    if bits > MAX_WIDTH as usize {
+        return Err(line.error(format!(
+            "expected at most {} bits, found {}",
+            MAX_WIDTH, bits
+        )));
+    }
+    match *width {
+        None => *width = Some(bits as u32),
+        Some(w) if w as usize != bits => {
+            return Err(line.error(format!("expected {} bits, found {}", w, bits)))
+        }
+        _ => {}
+    }
+    Ok(u128::from_str_radix(line.text, 2).unwrap())
+}
+
+/// Check every line is a binary number of the same width, of at most 128 bits
+pub fn parse_report(input: &str) -> Result<Report, ParseError> {
+    let mut width = None;
+    let numbers = parse::lines(3, input)
+        .map(|line| read_number(line, &mut width))
+        .collect::<Result<Vec<_>, _>>()?;
+    match width {
+        Some(width) => Ok(Report { width, numbers }),
+        None => Err(parse::unexpected_end(
+            3,
+            input,
+            "expected at least one number",
+        )),
+    }
+}
+
+/// How many numbers have a one in each bit, counting from the least significant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitCounts {
+    pub numbers: u64,
+    pub ones: [u64; MAX_WIDTH as usize],
+}
+
+impl Default for BitCounts {
+    fn default() -> Self {
+        BitCounts {
+            numbers: 0,
+            ones: [0; MAX_WIDTH as usize],
+        }
+    }
+}
+
+impl BitCounts {
+    /// Count `number`'s ones, visiting only the bits which are set
+    pub fn add(&mut self, mut number: u128) {
+        self.numbers += 1;
+        while number != 0 {
+            self.ones[number.trailing_zeros() as usize] += 1;
+            number &= number - 1;
+        }
+    }
+
+    /// The most common value of each of the low `width` bits, with ties going to zero
+    pub fn gamma_rate(&self, width: u32) -> u128 {
+        (0..width)
+            .filter(|&bit| self.ones[bit as usize] * 2 > self.numbers)
+            .fold(0, |gamma, bit| gamma | 1 << bit)
+    }
+}
+
+impl Extend<u128> for BitCounts {
+    fn extend<I: IntoIterator<Item = u128>>(&mut self, numbers: I) {
+        for number in numbers {
+            self.add(number);
+        }
+    }
+}
+
+pub fn part1(report: &Report) -> Answer {
+    let mut counts = BitCounts::default();
+    counts.extend(report.numbers.iter().copied());
+    power_consumption(&counts, report.width)
+}
+
+/// Part 1 from one pass over `reader`, holding only a count per bit
+pub fn stream_part1(reader: impl BufRead) -> Result<Answer, StreamError> {
+    let mut width = None;
+    let mut counts = BitCounts::default();
+    let lines = parse::for_each_line(3, reader, |line| {
+        counts.add(read_number(line, &mut width)?);
+        Ok(())
+    })?;
+    match width {
+        Some(width) => Ok(power_consumption(&counts, width)),
+        None => Err(parse::unexpected_end_after(3, lines, "expected at least one number").into()),
+    }
+}
+
+fn power_consumption(counts: &BitCounts, width: u32) -> Answer {
+    let gamma_rate = counts.gamma_rate(width);
+    let epsilon_rate = !gamma_rate & mask(width);
+    product(gamma_rate, epsilon_rate)
+}
+
+/// Which rating to find by narrowing down the numbers a bit at a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Criteria {
+    /// Keep the most common bit, or ones on a tie
+    Oxygen,
+    /// Keep the least common bit, or zeros on a tie
+    Co2,
+}
+
+/// Narrow `sorted` down to one number, most significant bit first
+///
+/// Numbers agreeing on the bits seen so far are a contiguous run of `sorted`, split in two by
+/// the next bit, so each step is a binary search rather than a pass over every number
+fn rating(sorted: &[u128], width: u32, criteria: Criteria) -> u128 {
+    let mut candidates = sorted;
+    for bit in (0..width).rev() {
+        if candidates.len() == 1 {
+            break;
+        }
+        let split = candidates.partition_point(|n| n >> bit & 1 == 0);
+        let (zeros, ones) = candidates.split_at(split);
+        candidates = match criteria {
+            _ if zeros.is_empty() => ones,
+            _ if ones.is_empty() => zeros,
+            Criteria::Oxygen if ones.len() >= zeros.len() => ones,
+            Criteria::Co2 if zeros.len() <= ones.len() => zeros,
+            Criteria::Oxygen => zeros,
+            Criteria::Co2 => ones,
+        };
+    }
+    candidates[0]
+}
+
+pub fn part2(report: &Report) -> Answer {
+    let mut sorted = report.numbers.clone();
+    sorted.sort_unstable();
+    let oxygen_generator_rating = rating(&sorted, report.width, Criteria::Oxygen);
+    let co2_scrubber_rating = rating(&sorted, report.width, Criteria::Co2);
+    product(oxygen_generator_rating, co2_scrubber_rating)
+}
+
+/// `a * b`, which for reports wider than 64 bits may not fit in any integer answer, so is
+/// then given as decimal text
+fn product(a: u128, b: u128) -> Answer {
+    match a.checked_mul(b).map(i128::try_from) {
+        Some(Ok(n)) => Answer::integer(n),
+        _ => Answer::Text(wide_product(a, b)),
+    }
+}
+
+/// The decimal digits of `a * b`, worked out in 64-bit limbs
+fn wide_product(a: u128, b: u128) -> String {
+    let halves = |n: u128| [n as u64, (n >> 64) as u64];
+    let mut limbs = [0u64; 4];
+    for (i, x) in halves(a).into_iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, y) in halves(b).into_iter().enumerate() {
+            let sum = u128::from(x) * u128::from(y) + u128::from(limbs[i + j]) + carry;
+            limbs[i + j] = sum as u64;
+            carry = sum >> 64;
+        }
+        limbs[i + 2] = carry as u64;
+    }
+
+    // Divide by 10^19, the largest power of ten in a limb, until nothing is left
+    const CHUNK: u64 = 10_000_000_000_000_000_000;
+    let mut chunks = Vec::new();
+    while limbs.iter().any(|&limb| limb != 0) {
+        let mut remainder = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let value = remainder << 64 | u128::from(*limb);
+            *limb = (value / u128::from(CHUNK)) as u64;
+            remainder = value % u128::from(CHUNK);
+        }
+        chunks.push(remainder as u64);
+    }
+    match chunks.split_last() {
+        None => "0".to_string(),
+        Some((most, rest)) => {
+            let mut digits = most.to_string();
+            for chunk in rest.iter().rev() {
+                digits.push_str(&format!("{:019}", chunk));
+            }
+            digits
+        }
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Report;
+    type Output1 = Answer;
+    type Output2 = Answer;
+
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_report(input)
+    }
+
+    fn part1(report: &Self::Input) -> Answer {
+        part1(report)
+    }
+
+    fn part2(report: &Self::Input) -> Answer {
+        part2(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_part1() {
+        let input = include_str!("../../fixtures/2021/day3.txt");
+        assert_eq!(
+            stream_part1(input.as_bytes()).unwrap(),
+            part1(&parse_report(input).unwrap())
+        );
+        assert!(matches!(
+            stream_part1("\n\n".as_bytes()),
+            Err(StreamError::Parse(e)) if e.line == 1
+        ));
+        assert!(matches!(
+            stream_part1("101\n11\n".as_bytes()),
+            Err(StreamError::Parse(e)) if e.line == 2
+        ));
+    }
+
+    #[test]
+    fn test_parse_report() {
+        let report = parse_report("00100\n11110\n").unwrap();
+        assert_eq!(
+            report,
+            Report {
+                width: 5,
+                numbers: vec![0b00100, 0b11110]
+            }
+        );
+        assert_eq!(report.mask(), 0b11111);
+
+        let error = parse_report("00100\n11210").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = parse_report("00100\n1111").unwrap_err();
+        assert_eq!(error.line, 2);
+        let error = parse_report(&"1".repeat(129)).unwrap_err();
+        assert_eq!(error.message, "expected at most 128 bits, found 129");
+    }
+
+    #[test]
+    fn test_bit_counts() {
+        let mut counts = BitCounts::default();
+        counts.extend([0b110, 0b011, 0b010, 1 << 127]);
+        assert_eq!(counts.numbers, 4);
+        assert_eq!(&counts.ones[..3], &[1, 3, 1]);
+        assert_eq!(counts.ones[127], 1);
+        assert_eq!(counts.gamma_rate(128), 0b010);
+    }
+
+    #[test]
+    fn test_128_bit_report() {
+        let report = Report {
+            width: 128,
+            numbers: vec![u128::MAX, u128::MAX, 1],
+        };
+        // Gamma is every bit and epsilon none
+        assert_eq!(part1(&report), Answer::integer(0));
+        // Oxygen keeps the two all-ones numbers, CO2 the one
+        assert_eq!(part2(&report).to_string(), u128::MAX.to_string());
+
+        let report = Report {
+            width: 128,
+            numbers: vec![u128::MAX >> 1, u128::MAX >> 1, 1 << 127],
+        };
+        assert_eq!(
+            part1(&report).to_string(),
+            "28948022309329048855892746252171976963147354982949671778132708698262398304256"
+        );
+    }
+
+    #[test]
+    fn test_wide_product() {
+        assert_eq!(wide_product(0, u128::MAX), "0");
+        assert_eq!(wide_product(12, 34), "408");
+        assert_eq!(
+            wide_product(u128::MAX, u128::MAX),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+    }
+
+    #[test]
+    fn test_trie() {
+        let trie = WeightedTrie::new(2);
+        assert_eq!((trie.height, trie.radix), (2, 2));
+        assert!(trie.is_empty());
+        assert_eq!(trie.values_under("").next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Must be positive height")]
+    fn test_zero_height_trie() {
+        WeightedTrie::new(0);
+    }
+
+    #[test]
+    fn test_trie_insert() {
+        let mut trie = WeightedTrie::new(2);
+        trie.insert("10").unwrap();
+        trie.insert("11").unwrap();
+        trie.insert("11").unwrap();
+        trie.insert("0").unwrap();
+        assert_eq!(trie.len(), 4);
+        assert_eq!(trie.count_prefix("1"), 3);
+        assert_eq!(trie.count_prefix("0"), 1);
+        assert_eq!(trie.count("11"), 2);
+        assert_eq!(trie.count("1"), 0);
+        assert_eq!(
+            trie.insert("101"),
+            Err(TrieError::TooLong {
+                height: 2,
+                length: 3
+            })
+        );
+        assert_eq!(
+            trie.insert("12").unwrap_err().to_string(),
+            "'2' is not a base 2 digit"
+        );
+        assert_eq!(trie.len(), 4);
+    }
+
+    #[test]
+    fn test_trie_remove() {
+        let mut trie = WeightedTrie::new(3);
+        for value in ["101", "101", "100", "0"] {
+            trie.insert(value).unwrap();
+        }
+        let nodes = trie.nodes.len();
+        assert_eq!(trie.remove("10"), Ok(false));
+        assert_eq!(trie.remove("111"), Ok(false));
+        assert_eq!(trie.remove("101"), Ok(true));
+        assert_eq!(trie.count("101"), 1);
+        assert_eq!(trie.remove("101"), Ok(true));
+        assert_eq!(trie.remove("101"), Ok(false));
+        assert_eq!(trie.count_prefix("10"), 1);
+        assert_eq!(trie.remove("100"), Ok(true));
+        assert_eq!(trie.count_prefix("1"), 0);
+        assert_eq!(trie.len(), 1);
+
+        // Freed nodes are reused
+        trie.insert("111").unwrap();
+        assert_eq!(trie.nodes.len(), nodes);
+        assert_eq!(trie.count_prefix("1"), 1);
+    }
+
+    #[test]
+    fn test_trie_values_under() {
+        let mut trie = WeightedTrie::with_radix(4, 16);
+        for value in ["beef", "bee", "bead", "beef", "cafe", "b"] {
+            trie.insert(value).unwrap();
+        }
+        let values: Vec<(String, usize)> = trie.values_under("be").collect();
+        assert_eq!(
+            values,
+            vec![
+                ("bead".to_string(), 1),
+                ("bee".to_string(), 1),
+                ("beef".to_string(), 2)
+            ]
+        );
+        assert_eq!(trie.values_under("").count(), 5);
+        assert_eq!(trie.values_under("f").count(), 0);
+        assert_eq!(trie.values_under("xyz").count(), 0);
+        let values: Vec<(String, usize)> = trie.values_under("BEE").collect();
+        assert_eq!(
+            values,
+            vec![("bee".to_string(), 1), ("beef".to_string(), 2)]
+        );
+        assert_eq!(trie.count_prefix("b"), 5);
+    }
+
+    #[test]
+    fn test_tall_trie() {
+        let mut trie = WeightedTrie::new(128);
+        let value = "10".repeat(64);
+        trie.insert(&value).unwrap();
+        assert_eq!(trie.nodes.len(), 129);
+        assert_eq!(trie.count_prefix(&value[..100]), 1);
+        assert_eq!(trie.remove(&value), Ok(true));
+        assert!(trie.is_empty());
+    }
+}
@@ -1,44 +1,76 @@
-use aoc_runner_derive::{aoc, aoc_generator};
 use std::collections::HashSet;
 
-type Draw = Vec<usize>;
+use crate::{
+    parse::{self, Line, ParseError},
+    solution::Solution,
+};
 
-type Board = Vec<Vec<usize>>;
+/// The numbers drawn, in order
+pub type Draw = Vec<usize>;
 
-type Boards = Vec<Board>;
+/// A 5x5 bingo board, indexed by row then column
+pub type Board = Vec<Vec<usize>>;
 
-fn parse_draw(input: &str) -> Draw {
-    input
-        .split(',')
-        .map(|n| str::parse::<usize>(n).unwrap())
-        .collect()
+pub type Boards = Vec<Board>;
+
+fn parse_draw(line: Line) -> Result<Draw, ParseError> {
+    line.text.split(',').map(|n| line.parse(n)).collect()
 }
 
-fn parse_board<'a>(input: impl Iterator<Item = &'a str>) -> Board {
+fn parse_board<'a>(input: impl Iterator<Item = Line<'a>>) -> Result<Board, ParseError> {
     let mut board: Board = Vec::new();
     for line in input {
         // line = "10  0 12 27 5"
-        board.push(
-            line.split_whitespace()
-                .map(|x| str::parse::<usize>(x).unwrap())
-                .collect(),
-        )
+        let row = line
+            .text
+            .split_whitespace()
+            .map(|x| line.parse(x))
+            .collect::<Result<Vec<usize>, _>>()?;
+        if row.len() != 5 {
+            return Err(line.error(format!("expected 5 numbers per row, found {}", row.len())));
+        }
+        board.push(row)
     }
-    board
+    Ok(board)
 }
 
-#[aoc_generator(day4)]
-fn parse_input(input: &str) -> (Draw, Boards) {
-    let lines: Vec<&str> = input.lines().collect();
-    let draw = parse_draw(lines[0]);
+pub fn parse_input(input: &str) -> Result<(Draw, Boards), ParseError> {
+    let lines: Vec<Line> = parse::lines(4, input).collect();
+    let draw = match lines.first() {
+        Some(&line) => parse_draw(line)?,
+        None => {
+            return Err(parse::unexpected_end(
+                4,
+                input,
+                "expected the numbers drawn",
+            ))
+        }
+    };
 
     let mut boards = Vec::new();
 
     for row in (2..lines.len()).step_by(6) {
-        boards.push(parse_board(lines[row..row + 5].iter().cloned()))
+        let separator = lines[row - 1];
+        if !separator.text.is_empty() {
+            return Err(separator.error("expected a blank line before each board"));
+        }
+        if row + 5 > lines.len() {
+            return Err(parse::unexpected_end(4, input, "expected 5 rows per board"));
+        }
+        boards.push(parse_board(lines[row..row + 5].iter().copied())?)
+    }
+
+    // The loop stops short of a last board with no room for its rows, so anything still left
+    // is a line too many
+    let used = 1 + 6 * boards.len();
+    if let Some(&line) = lines.get(used) {
+        return Err(match line.text.is_empty() {
+            true => parse::unexpected_end(4, input, "expected 5 rows per board"),
+            false => line.error("expected a blank line before each board"),
+        });
     }
 
-    (draw, boards)
+    Ok((draw, boards))
 }
 
 fn score(draw: HashSet<usize>, board: &[Vec<usize>], last_number: usize) -> usize {
@@ -63,8 +95,9 @@ fn has_won(board: &[Vec<usize>], drawn: &HashSet<usize>) -> bool {
     }
     // check columns
     for column_idx in 0..5 {
-        if (0..5)
-            .map(|row| board[row][column_idx])
+        if board
+            .iter()
+            .map(|row| row[column_idx])
             .all(|n| drawn.contains(&n))
         {
             return true;
@@ -118,67 +151,72 @@ fn find_last_winning_board<'a>(
     panic!("No last winning board found");
 }
 
-#[aoc(day4, part1)]
 pub fn part_1((draw, boards): &(Draw, Boards)) -> usize {
     let (winning_board, drawn, last_number_drawn) = find_first_winning_board(draw, boards);
 
     score(drawn, winning_board, last_number_drawn)
 }
 
-#[aoc(day4, part2)]
 pub fn part_2((draw, boards): &(Draw, Boards)) -> usize {
     let (winning_board, drawn, last_number_drawn) = find_last_winning_board(draw, boards);
 
     score(drawn, winning_board, last_number_drawn)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Day4;
 
-    const EXAMPLE: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+impl Solution for Day4 {
+    type Input = (Draw, Boards);
+    type Output1 = usize;
+    type Output2 = usize;
 
-22 13 17 11  0
- 8  2 23  4 24
-21  9 14 16  7
- 6 10  3 18  5
- 1 12 20 15 19
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Giant Squid";
 
- 3 15  0  2 22
- 9 18 13 17  5
-19  8  7 25 23
-20 11 10 24  4
-14 21 16 12  6
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
 
-14 21 17 24  4
-10 16 15  9 19
-18  8 23 26 20
-22 11 13  6  5
- 2  0 12  3  7";
+    fn part1(bingo: &Self::Input) -> usize {
+        part_1(bingo)
+    }
+
+    fn part2(bingo: &Self::Input) -> usize {
+        part_2(bingo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn test_parse_draw() {
-        assert_eq!(parse_draw("7,4,9,5,11"), vec![7, 4, 9, 5, 11])
+        assert_eq!(
+            parse_draw(parse::lines(4, "7,4,9,5,11").next().unwrap()),
+            Ok(vec![7, 4, 9, 5, 11])
+        )
     }
 
     #[test]
     fn test_parse_board() {
         assert_eq!(
-            parse_board(
+            parse_board(parse::lines(
+                4,
                 "22 13 17 11  0
  8  2 23  4 24
 21  9 14 16  7
  6 10  3 18  5
  1 12 20 15 19"
-                    .lines()
-            ),
-            vec![
+            )),
+            Ok(vec![
                 vec![22, 13, 17, 11, 0],
                 vec![8, 2, 23, 4, 24],
                 vec![21, 9, 14, 16, 7],
                 vec![6, 10, 3, 18, 5],
                 vec![1, 12, 20, 15, 19]
-            ]
+            ])
         )
     }
 
@@ -202,12 +240,20 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        assert_eq!(part_1(&parse_input(EXAMPLE)), 4512);
+    fn test_parse_input_reports_stray_line() {
+        let board = "1 2 3 4 5\n".repeat(5);
+        let error = parse_input(&format!("1,2\n\n{}garbage here\n", board)).unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (8, "expected a blank line before each board")
+        );
+        let error = parse_input(&format!("1,2\n\n{}\n", board)).unwrap_err();
+        assert_eq!(error.line, 9);
     }
 
     #[test]
-    fn test_part_2() {
-        assert_eq!(part_2(&parse_input(EXAMPLE)), 1924);
+    fn test_parse_input_reports_short_board() {
+        let error = parse_input("1,2,3\n\n1 2 3 4 5\n1 2 3 4").unwrap_err();
+        assert_eq!(error.line, 5);
     }
 }
@@ -0,0 +1,200 @@
+use std::io::BufRead;
+
+use crate::{
+    counter::Counter,
+    parse::{self, ParseError, StreamError},
+    point::Point2,
+    solution::Solution,
+};
+
+pub type Point = Point2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Line {
+    fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    fn points(&self) -> impl Iterator<Item = Point> {
+        self.start.segment_to(self.end)
+    }
+}
+
+pub type Lines = Vec<Line>;
+
+fn parse_point(line: parse::Line, text: &str) -> Result<Point, ParseError> {
+    let (x, y) = text
+        .split_once(',')
+        .ok_or_else(|| line.error_at(text, "expected a point of the form `x,y`"))?;
+    Ok(Point2::new(line.parse(x)?, line.parse(y)?))
+}
+
+fn parse_line(line: parse::Line) -> Result<Line, ParseError> {
+    let (start_text, end_text) = line.split_once(" -> ")?;
+    let (start, end) = (parse_point(line, start_text)?, parse_point(line, end_text)?);
+    // How far the line goes along each axis, if that fits in an `i64`
+    let distance = |from: i64, to: i64| to.checked_sub(from).and_then(i64::checked_abs);
+    let (Some(dx), Some(dy)) = (distance(start.x, end.x), distance(start.y, end.y)) else {
+        return Err(line.error_at(
+            end_text,
+            "expected an end point less than 2^63 from the start",
+        ));
+    };
+    if dx != 0 && dy != 0 && dx != dy {
+        return Err(line.error("expected a horizontal, vertical or diagonal line"));
+    }
+    Ok(Line { start, end })
+}
+
+pub fn parse_lines(input: &str) -> Result<Lines, ParseError> {
+    parse::lines(5, input).map(parse_line).collect()
+}
+
+/// How many points are covered by at least two lines
+fn count_overlaps<'a>(lines: impl Iterator<Item = &'a Line>) -> usize {
+    overlaps(&lines.flat_map(Line::points).collect())
+}
+
+fn overlaps(covered: &Counter<Point>) -> usize {
+    covered.iter().filter(|&(_, count)| count >= 2).count()
+}
+
+pub fn part_1(lines: &[Line]) -> usize {
+    count_overlaps(lines.iter().filter(|l| l.is_horizontal() | l.is_vertical()))
+}
+
+pub fn part_2(lines: &[Line]) -> usize {
+    count_overlaps(lines.iter())
+}
+
+/// Both answers from one pass over `reader`, holding how often each point is covered rather
+/// than the lines themselves
+pub fn stream(reader: impl BufRead) -> Result<(usize, usize), StreamError> {
+    let mut straight = Counter::new();
+    let mut all = Counter::new();
+    parse::for_each_line(5, reader, |line| {
+        let line = parse_line(line)?;
+        if line.is_horizontal() | line.is_vertical() {
+            straight.extend(line.points());
+        }
+        all.extend(line.points());
+        Ok(())
+    })?;
+    Ok((overlaps(&straight), overlaps(&all)))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Lines;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(lines: &Self::Input) -> usize {
+        part_1(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> usize {
+        part_2(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_vertical() {
+        assert!(Line {
+            start: Point2::new(1, 1),
+            end: Point2::new(1, 3)
+        }
+        .is_vertical());
+        assert!(!Line {
+            start: Point2::new(9, 7),
+            end: Point2::new(7, 7)
+        }
+        .is_vertical())
+    }
+
+    #[test]
+    fn test_is_horizontal() {
+        assert!(!Line {
+            start: Point2::new(1, 1),
+            end: Point2::new(1, 3)
+        }
+        .is_horizontal());
+        assert!(Line {
+            start: Point2::new(9, 7),
+            end: Point2::new(7, 7)
+        }
+        .is_horizontal())
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines("1,1 -> 1,3\n9,7 -> 7,7"),
+            Ok(vec![
+                Line {
+                    start: Point2::new(1, 1),
+                    end: Point2::new(1, 3)
+                },
+                Line {
+                    start: Point2::new(9, 7),
+                    end: Point2::new(7, 7)
+                }
+            ])
+        )
+    }
+
+    #[test]
+    fn test_stream() {
+        let input = include_str!("../../fixtures/2021/day5.txt");
+        let lines = parse_lines(input).unwrap();
+        assert_eq!(
+            stream(input.as_bytes()).unwrap(),
+            (part_1(&lines), part_2(&lines))
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_reports_bad_coordinate() {
+        let error = parse_lines("1,1 -> 1,3\n9,7 -> 7;7").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 8));
+    }
+
+    #[test]
+    fn test_parse_lines_rejects_other_angles() {
+        assert_eq!(parse_lines("0,0 -> 1,2").unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn test_parse_lines_rejects_overlong_lines() {
+        for input in [
+            "-9223372036854775808,0 -> 9223372036854775807,0",
+            "0,0 -> 0,-9223372036854775808",
+        ] {
+            let error = parse_lines(input).unwrap_err();
+            assert_eq!(error.column, input.find(" -> ").unwrap() + 5);
+        }
+        assert!(parse_lines("-1,0 -> 9223372036854775806,0").is_ok());
+    }
+}
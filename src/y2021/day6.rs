@@ -0,0 +1,110 @@
+use crate::{
+    memo::Memo,
+    parse::{self, ParseError},
+    solution::{Params, Solution},
+};
+
+pub fn parse_ages(input: &str) -> Result<Vec<usize>, ParseError> {
+    let line = parse::lines(6, input)
+        .next()
+        .ok_or_else(|| parse::unexpected_end(6, input, "expected a comma separated list"))?;
+    line.text.split(',').map(|x| line.parse(x)).collect()
+}
+
+/// Fish counts already worked out, by `(timer, days)`
+pub type FishMemo = Memo<(usize, usize), usize>;
+
+/// How many fish one fish with `timer` becomes after `days`
+pub fn fish_after(memo: &mut FishMemo, timer: usize, days: usize) -> usize {
+    if days <= timer {
+        return 1;
+    }
+    if let Some(&fish) = memo.get(&(timer, days)) {
+        return fish;
+    }
+
+    let remaining = days - 1;
+    let fish = if timer == 0 {
+        fish_after(memo, 6, remaining) + fish_after(memo, 8, remaining)
+    } else {
+        fish_after(memo, timer - 1, remaining)
+    };
+    memo.insert((timer, days), fish);
+    fish
+}
+
+/// The number of fish after `days`, sharing one memo table between every fish
+pub fn count_fish(ages: &[usize], days: usize) -> usize {
+    let mut memo = FishMemo::new();
+    ages.iter()
+        .map(|&fish| fish_after(&mut memo, fish, days))
+        .sum()
+}
+
+pub fn part_1(ages: &[usize]) -> usize {
+    count_fish(ages, 80)
+}
+
+pub fn part_2(ages: &[usize]) -> usize {
+    count_fish(ages, 256)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Lanternfish";
+    const PARAMS: &'static [&'static str] = &["days"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_ages(input)
+    }
+
+    fn part1(ages: &Self::Input) -> usize {
+        part_1(ages)
+    }
+
+    fn part2(ages: &Self::Input) -> usize {
+        part_2(ages)
+    }
+
+    fn part1_with(ages: &Self::Input, params: &Params) -> usize {
+        count_fish(ages, params.count_or("days", 80))
+    }
+
+    fn part2_with(ages: &Self::Input, params: &Params) -> usize {
+        count_fish(ages, params.count_or("days", 256))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fish_after() {
+        let mut memo = FishMemo::new();
+        assert_eq!(fish_after(&mut memo, 1, 1), 1);
+        assert_eq!(fish_after(&mut memo, 1, 2), 2);
+        assert_eq!(fish_after(&mut memo, 1, 9), 3);
+        assert_eq!(fish_after(&mut memo, 1, 11), 4);
+    }
+
+    #[test]
+    fn test_memo_capacity() {
+        let mut memo = FishMemo::new();
+        assert_eq!(fish_after(&mut memo, 3, 80), 1154);
+        let stats = memo.stats();
+        assert!(stats.hits > 0);
+        // A bounded table gives the same answer, just with more work
+        let mut bounded = FishMemo::with_capacity_limit(8);
+        assert_eq!(fish_after(&mut bounded, 3, 80), 1154);
+        assert_eq!(bounded.len(), 8);
+        assert!(bounded.stats().misses > stats.misses);
+    }
+}
@@ -1,42 +1,35 @@
-use std::collections::HashMap;
-
-use aoc_runner_derive::{aoc, aoc_generator};
-use cached::proc_macro::cached;
-
-#[aoc_generator(day7)]
-fn parse_positions(input: &str) -> Vec<usize> {
-    input
-        .lines()
+use crate::{
+    counter::Counter,
+    memo::Memo,
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+pub fn parse_positions(input: &str) -> Result<Vec<usize>, ParseError> {
+    let line = parse::lines(7, input)
         .next()
-        .unwrap()
-        .split(',')
-        .map(|x| str::parse(x).unwrap())
-        .collect()
+        .ok_or_else(|| parse::unexpected_end(7, input, "expected a comma separated list"))?;
+    line.text.split(',').map(|x| line.parse(x)).collect()
 }
 
-#[aoc(day7, part1)]
 pub fn part_1(positions: &[usize]) -> usize {
     let median = median(&mut positions.to_vec());
 
     let deltas = positions
         .iter()
-        .map(|x| (*x as i32 - median as i32).abs() as usize);
+        .map(|x| (*x as i32 - median as i32).unsigned_abs() as usize);
 
     deltas.sum()
 }
 
-#[aoc(day7, part2)]
 pub fn part_2(positions: &[usize]) -> usize {
-    let mut counters = HashMap::new();
-    positions.iter().for_each(|&position| {
-        let counter = counters.entry(position).or_insert(0);
-        *counter += 1;
-    });
+    let counters: Counter<usize> = positions.iter().copied().collect();
 
     let min = *counters.keys().min().unwrap();
     let max = *counters.keys().max().unwrap();
 
     let mut least_fuel: usize = usize::MAX;
+    let mut triangular_numbers = Memo::new();
 
     // NB: If additional performance is needed, can either:
     // 1. Start at the median/mean, and explore either side. Continue on the side where we see a decrease
@@ -48,9 +41,13 @@ pub fn part_2(positions: &[usize]) -> usize {
             .map(|(k, count)| {
                 let fuel_for_position = match *k == position {
                     true => 0,
-                    false => triangular_number_sum(abs_diff(k, &position)),
+                    false => {
+                        let distance = abs_diff(k, &position);
+                        triangular_numbers
+                            .get_or_insert_with(distance, || triangular_number_sum(distance))
+                    }
                 };
-                fuel_for_position * count
+                fuel_for_position * count as usize
             })
             .sum();
 
@@ -73,7 +70,7 @@ fn median(numbers: &mut [usize]) -> usize {
     numbers.sort_unstable();
 
     let mid = numbers.len() / 2;
-    if numbers.len() % 2 == 0 {
+    if numbers.len().is_multiple_of(2) {
         average(&[numbers[mid - 1], numbers[mid]]) as usize
     } else {
         numbers[mid]
@@ -81,27 +78,33 @@ fn median(numbers: &mut [usize]) -> usize {
 }
 
 fn abs_diff(a: &usize, b: &usize) -> usize {
-    (*a as i32 - *b as i32).abs() as usize
+    (*a as i32 - *b as i32).unsigned_abs() as usize
 }
 
-#[cached]
 pub fn triangular_number_sum(num: usize) -> usize {
     (0..num + 1).sum()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    const EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14";
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_positions(input)
+    }
 
-    #[test]
-    fn test_part_1() {
-        assert_eq!(part_1(&parse_positions(EXAMPLE)), 37);
+    fn part1(positions: &Self::Input) -> usize {
+        part_1(positions)
     }
 
-    #[test]
-    fn test_part_2() {
-        assert_eq!(part_2(&parse_positions(EXAMPLE)), 168);
+    fn part2(positions: &Self::Input) -> usize {
+        part_2(positions)
     }
 }
@@ -1,12 +1,18 @@
 use std::collections::{BTreeSet, HashMap, HashSet};
 
-use aoc_runner_derive::aoc;
+use crate::{
+    counter::Counter,
+    parse::{self, ParseError},
+    solution::Solution,
+};
 
-type Signals<'a> = Vec<&'a str>;
+/// The ten unique signal patterns
+pub type Signals = Vec<String>;
 
-type Output<'a> = Vec<&'a str>;
+/// The four digits of the output value
+pub type Output = Vec<String>;
 
-type Entry<'a> = (Signals<'a>, Output<'a>);
+pub type Entry = (Signals, Output);
 
 fn find_extra_char(a: &str, b: &str) -> char {
     // get which one is shorter
@@ -23,7 +29,7 @@ fn find_extra_char(a: &str, b: &str) -> char {
     panic!("No extra character")
 }
 
-fn decode_segments_map<'a>(signals: &'a [&str]) -> HashMap<&'a str, usize> {
+fn decode_segments_map(signals: &[String]) -> HashMap<&str, usize> {
     // 2: 1
     // 3: 7
     // 4: 4
@@ -41,21 +47,14 @@ fn decode_segments_map<'a>(signals: &'a [&str]) -> HashMap<&'a str, usize> {
     // Only 6 segment with missing 'e' => 9
     // Remaining number => 0
     let mut digits: HashMap<&str, usize> = HashMap::new();
-    for &signal in signals {
+    for signal in signals {
         if let Some(digit) = decode_unique_digits(signal) {
             digits.insert(signal, digit);
         }
     }
     let one: &str = digits.iter().find(|(_k, &v)| v == 1usize).unwrap().0;
 
-    let character_count: HashMap<char, usize> =
-        signals.iter().fold(HashMap::new(), |mut map, signal| {
-            signal.chars().for_each(|c| {
-                let counter = map.entry(c).or_insert(0);
-                *counter += 1;
-            });
-            map
-        });
+    let character_count: Counter<char> = signals.iter().flat_map(|s| s.chars()).collect();
 
     let &f = character_count
         .iter()
@@ -65,21 +64,9 @@ fn decode_segments_map<'a>(signals: &'a [&str]) -> HashMap<&'a str, usize> {
         })
         .unwrap();
 
-    let two: &str = signals
-        .iter()
-        .find_map(|signal| {
-            if !signal.contains(f) {
-                Some(signal)
-            } else {
-                None
-            }
-        })
-        .unwrap();
+    let two: &str = signals.iter().find(|signal| !signal.contains(f)).unwrap();
 
-    let c: char = one
-        .chars()
-        .find_map(|ch| if ch != f { Some(ch) } else { None })
-        .unwrap();
+    let c: char = one.chars().find(|&ch| ch != f).unwrap();
 
     let six: &str = signals
         .iter()
@@ -93,7 +80,7 @@ fn decode_segments_map<'a>(signals: &'a [&str]) -> HashMap<&'a str, usize> {
 
     let three: &str = signals
         .iter()
-        .find(|&signal| signal.len() == 5 && signal != &two && signal != &five)
+        .find(|&signal| signal.len() == 5 && signal != two && signal != five)
         .unwrap();
 
     let e = find_extra_char(six, five);
@@ -105,7 +92,7 @@ fn decode_segments_map<'a>(signals: &'a [&str]) -> HashMap<&'a str, usize> {
 
     let zero: &str = signals
         .iter()
-        .find(|&signal| signal.len() == 6 && signal != &six && signal != &nine)
+        .find(|&signal| signal.len() == 6 && signal != six && signal != nine)
         .unwrap();
 
     digits.insert(two, 2);
@@ -128,21 +115,32 @@ fn decode_unique_digits(signal: &str) -> Option<usize> {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Entry> {
-    input
-        .lines()
-        .map(|l| {
-            let mut contents = l.split(" | ");
-            let signals: Signals = contents.next().unwrap().split(' ').collect();
-            let output: Output = contents.next().unwrap().split(' ').collect();
-            (signals, output)
+pub fn parse_input(input: &str) -> Result<Vec<Entry>, ParseError> {
+    parse::lines(8, input)
+        .map(|line| {
+            if let Some((i, c)) = line
+                .text
+                .chars()
+                .enumerate()
+                .find(|(_, c)| !matches!(c, 'a'..='g' | ' ' | '|'))
+            {
+                return Err(line.error_at_column(i + 1, format!("unexpected character {:?}", c)));
+            }
+            let (signals, output) = line.split_once(" | ")?;
+            let signals: Signals = signals.split(' ').map(String::from).collect();
+            let output: Output = output.split(' ').map(String::from).collect();
+            if signals.len() != 10 {
+                return Err(line.error(format!(
+                    "expected 10 signal patterns, found {}",
+                    signals.len()
+                )));
+            }
+            Ok((signals, output))
         })
         .collect()
 }
 
-#[aoc(day8, part1)]
-pub fn part_1(input: &str) -> usize {
-    let entries = parse_input(input);
+pub fn part_1(entries: &[Entry]) -> usize {
     entries
         .iter()
         .flat_map(|(_signals, output)| output)
@@ -153,9 +151,7 @@ pub fn part_1(input: &str) -> usize {
         .count()
 }
 
-#[aoc(day8, part2)]
-pub fn part_2(input: &str) -> usize {
-    let entries = parse_input(input);
+pub fn part_2(entries: &[Entry]) -> usize {
     entries
         .iter()
         .map(|(signals, output)| {
@@ -167,7 +163,7 @@ pub fn part_2(input: &str) -> usize {
                 .collect();
             output
                 .iter()
-                .map(|&digit| {
+                .map(|digit| {
                     let chars = BTreeSet::from_iter(digit.chars());
                     character_set_map.get(&chars).unwrap()
                 })
@@ -176,29 +172,39 @@ pub fn part_2(input: &str) -> usize {
         .sum()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Day8;
 
-    const EXAMPLE: &str =
-        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
-edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
-fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
-fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
-aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
-fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
-dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
-bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
-egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
-gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+impl Solution for Day8 {
+    type Input = Vec<Entry>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    #[test]
-    fn test_part_1() {
-        assert_eq!(part_1(EXAMPLE), 26);
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Seven Segment Search";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(entries: &Self::Input) -> usize {
+        part_1(entries)
+    }
+
+    fn part2(entries: &Self::Input) -> usize {
+        part_2(entries)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
-    fn test_part_2() {
-        assert_eq!(part_2(EXAMPLE), 61229);
+    fn test_parse_input_requires_separator() {
+        let error =
+            parse_input("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb fdgacbe")
+                .unwrap_err();
+        assert_eq!(error.line, 1);
     }
 }
@@ -0,0 +1,86 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet, VecDeque},
+};
+
+use crate::{
+    grid::{Grid, Position},
+    parse::ParseError,
+    solution::Solution,
+};
+
+pub fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_digits(9, input)
+}
+
+pub fn part_1(grid: &Grid<u32>) -> u32 {
+    let troughs = find_troughs(grid);
+    troughs.iter().map(|&trough| grid[trough] + 1).sum()
+}
+
+pub fn part_2(grid: &Grid<u32>) -> u32 {
+    let troughs = find_troughs(grid);
+    let basins = troughs.iter().map(|&trough| {
+        // Each basin has a minimum size of 3-5 (itself, plus all neighbours in bounds)
+        let mut size: u32 = 0;
+        let mut stack: VecDeque<Position> = VecDeque::from([trough]);
+        let mut seen: HashSet<Position> = HashSet::new();
+        while let Some(position) = stack.pop_front() {
+            if !seen.insert(position) || grid[position] == 9 {
+                continue;
+            };
+            size += 1;
+
+            stack.extend(grid.neighbours4(position));
+        }
+        size
+    });
+    select_k(basins, 3).into_iter().product()
+}
+
+fn select_k(iter: impl Iterator<Item = u32>, k: usize) -> Vec<u32> {
+    let mut h = BinaryHeap::new();
+    for item in iter {
+        h.push(Reverse(item));
+        if h.len() > k {
+            h.pop();
+        }
+    }
+    h.into_iter().map(|rev| rev.0).collect()
+}
+
+fn find_troughs(grid: &Grid<u32>) -> Vec<Position> {
+    grid.iter()
+        // No neighbour is as low
+        .filter(|&(position, &cell)| {
+            !grid
+                .neighbours4(position)
+                .any(|neighbour| grid[neighbour] <= cell)
+        })
+        .map(|(position, _)| position)
+        .collect()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Grid<u32>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> u32 {
+        part_1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> u32 {
+        part_2(grid)
+    }
+}
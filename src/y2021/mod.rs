@@ -0,0 +1,78 @@
+//! Advent of Code 2021
+
+use crate::{answer::Answer, error::Error, registry::Entry, runner, solution::Part};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub static DAYS: [Entry; 15] = [
+    Entry::of::<day1::Day1>(),
+    Entry::of::<day2::Day2>(),
+    Entry::of::<day3::Day3>(),
+    Entry::of::<day4::Day4>(),
+    Entry::of::<day5::Day5>(),
+    Entry::of::<day6::Day6>(),
+    Entry::of::<day7::Day7>(),
+    Entry::of::<day8::Day8>(),
+    Entry::of::<day9::Day9>(),
+    Entry::of::<day10::Day10>(),
+    Entry::of::<day11::Day11>(),
+    Entry::of::<day12::Day12>(),
+    Entry::of::<day13::Day13>(),
+    Entry::of::<day14::Day14>(),
+    Entry::of::<day15::Day15>(),
+];
+
+/// Solve one part of a day's puzzle from its raw input
+///
+/// A panicking solver is reported as `Error::Panic`, though the panic hook will still be called
+pub fn solve(day: u32, part: Part, input: &str) -> Result<Answer, Error> {
+    let entry = DAYS
+        .iter()
+        .find(|entry| entry.day == day)
+        .ok_or(Error::UnknownDay { year: 2021, day })?;
+    Ok(runner::catch_panic(|| entry.solve(part, input)).map_err(Error::Panic)??)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve(6, Part::One, "3,4,3,1,2"), Ok(Answer::from(5934)));
+        assert_eq!(
+            solve(25, Part::One, ""),
+            Err(Error::UnknownDay {
+                year: 2021,
+                day: 25
+            })
+        );
+        assert!(matches!(
+            solve(6, Part::One, "3,x"),
+            Err(Error::Parse(e)) if e.line == 1
+        ));
+    }
+
+    #[test]
+    fn test_solve_catches_panics() {
+        let input = "1,2\n\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5";
+        assert_eq!(
+            solve(4, Part::One, input),
+            Err(Error::Panic("No winning board".to_string()))
+        );
+    }
+}